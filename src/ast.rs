@@ -1,5 +1,13 @@
-use std::fmt::{Debug, Formatter, Error};
+use std::fmt::{Debug, Display, Formatter, Error};
+use std::mem;
+use format::{self, Spelling};
 
+/// How deeply expressions may be nested. The passes over the syntax tree
+/// recurse, and deeper trees overflow a 2 MiB thread stack in debug builds,
+/// so `recover::parse` rejects them.
+pub const MAX_DEPTH: usize = 500;
+
+#[derive(PartialEq)]
 pub enum Expr {
     Number(i32),
 	True,
@@ -7,9 +15,11 @@ pub enum Expr {
 	Str(String),
 	Char(char),
     Op(Opcode, Vec<Box<Expr>>),
+	/// Stands in for source that didn't parse, see `recover::parse`.
+	Error,
 }
 
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, PartialEq, Debug)]
 pub enum Opcode {
     Mul,
     Div,
@@ -26,6 +36,37 @@ pub enum Opcode {
 	Leq,
 }
 
+impl Expr {
+	/// How deeply the expression is nested, counting itself. Works without
+	/// recursion, so it's safe on trees of any depth.
+	pub fn depth(&self) -> usize {
+		let mut deepest = 0;
+		let mut pending = vec![(self, 1)];
+		while let Some((expr, depth)) = pending.pop() {
+			deepest = deepest.max(depth);
+			if let Expr::Op(_, ref operands) = *expr {
+				pending.extend(operands.iter().map(|operand| (&**operand, depth + 1)));
+			}
+		}
+		deepest
+	}
+}
+
+/// Dropping the operands one after the other instead of recursively, since
+/// the parser builds trees deeper than `MAX_DEPTH` before they're rejected.
+impl Drop for Expr {
+	fn drop(&mut self) {
+		if let Expr::Op(_, ref mut operands) = *self {
+			let mut pending = mem::take(operands);
+			while let Some(mut expr) = pending.pop() {
+				if let Expr::Op(_, ref mut operands) = *expr {
+					pending.append(operands);
+				}
+			}
+		}
+	}
+}
+
 impl Debug for Expr {
     fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
         use self::Expr::*;
@@ -33,9 +74,18 @@ impl Debug for Expr {
             Number(n) => write!(fmt, "{:?}", n),
 			True => write!(fmt, "True"),
 			False => write!(fmt, "False"),
-			Str(ref s) => write!(fmt, "»{:?}‹«", s),
-			Char(c) => write!(fmt, "›{:?}‹", c),
+			Str(ref s) => write!(fmt, "»{}«", s),
+			Char(c) => write!(fmt, "›{}‹", c),
             Op(op, ref operands) => write!(fmt, "{:?}{:?}", op, operands),
+			Error => write!(fmt, "Error"),
         }
     }
-}
\ No newline at end of file
+}
+
+/// Prints the expression as Moses source that parses back to it, see
+/// `format::format`.
+impl Display for Expr {
+	fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+		write!(fmt, "{}", format::format(self, Spelling::Ascii))
+	}
+}
@@ -0,0 +1,260 @@
+//! A language server for Moses, speaking the Language Server Protocol over
+//! stdin and stdout.
+//!
+//! It reports all parse errors and type errors as diagnostics and shows the type of a
+//! document on hover. Moses has no variables or definitions yet, so there is
+//! nothing to go to, complete or list as symbols.
+
+extern crate moses;
+extern crate serde_json;
+
+use std::collections::HashMap;
+use std::io::{self, BufRead, Write};
+use std::process;
+
+use moses::{recover, types};
+use serde_json::Value;
+
+/// The JSON-RPC error code for requests the server doesn't know.
+const METHOD_NOT_FOUND: i64 = -32601;
+/// The LSP severity of errors.
+const SEVERITY_ERROR: i64 = 1;
+/// Documents are always synchronized by sending their full text.
+const SYNC_FULL: i64 = 1;
+
+fn main() {
+	let stdin = io::stdin();
+	let stdout = io::stdout();
+	process::exit(serve(&mut stdin.lock(), &mut stdout.lock()));
+}
+
+/// Answers the messages from `input` on `output` until the client sends
+/// `exit`, and returns the exit status the protocol asks for.
+fn serve<R: BufRead, W: Write>(input: &mut R, output: &mut W) -> i32 {
+	let mut server = Server { documents: HashMap::new(), shut_down: false };
+	loop {
+		let message = match read_message(input) {
+			Ok(Some(message)) => message,
+			Ok(None) => return 1,
+			Err(err) => {
+				eprintln!("moses-lsp: {}", err);
+				return 1;
+			}
+		};
+		if message["method"] == "exit" {
+			return if server.shut_down { 0 } else { 1 };
+		}
+		for reply in server.handle(&message) {
+			if let Err(err) = write_message(output, &reply) {
+				eprintln!("moses-lsp: {}", err);
+				return 1;
+			}
+		}
+	}
+}
+
+struct Server {
+	/// The text of every open document by URI.
+	documents: HashMap<String, String>,
+	shut_down: bool,
+}
+
+impl Server {
+	/// Handles one request or notification and returns the messages to send.
+	fn handle(&mut self, message: &Value) -> Vec<Value> {
+		let params = &message["params"];
+		let uri = params["textDocument"]["uri"].as_str().unwrap_or("").to_string();
+		let result = match message["method"].as_str().unwrap_or("") {
+			"initialize" => json(&[
+				("capabilities", json(&[
+					("textDocumentSync", Value::from(SYNC_FULL)),
+					("hoverProvider", Value::from(true)),
+				])),
+				("serverInfo", json(&[("name", Value::from("moses-lsp"))])),
+			]),
+			"shutdown" => {
+				self.shut_down = true;
+				Value::Null
+			}
+			"textDocument/didOpen" => {
+				let text = params["textDocument"]["text"].as_str().unwrap_or("").to_string();
+				self.documents.insert(uri.clone(), text);
+				return vec![self.diagnostics(&uri)];
+			}
+			"textDocument/didChange" => {
+				// With full synchronization, the last change is the whole text.
+				if let Some(text) = params["contentChanges"].as_array()
+					.and_then(|changes| changes.last())
+					.and_then(|change| change["text"].as_str())
+				{
+					self.documents.insert(uri.clone(), text.to_string());
+				}
+				return vec![self.diagnostics(&uri)];
+			}
+			"textDocument/didClose" => {
+				self.documents.remove(&uri);
+				return vec![self.diagnostics(&uri)];
+			}
+			"textDocument/hover" => self.hover(&uri),
+			method => {
+				// Notifications we don't know are ignored, including the
+				// optional `$/` ones, requests get an error.
+				if message.get("id").is_none() {
+					return Vec::new();
+				}
+				return vec![json(&[
+					("jsonrpc", Value::from("2.0")),
+					("id", message["id"].clone()),
+					("error", json(&[
+						("code", Value::from(METHOD_NOT_FOUND)),
+						("message", Value::from(format!("unsupported method {}", method))),
+					])),
+				])];
+			}
+		};
+		match message.get("id") {
+			Some(id) => vec![json(&[("jsonrpc", Value::from("2.0")), ("id", id.clone()), ("result", result)])],
+			None => Vec::new(),
+		}
+	}
+
+	/// A `publishDiagnostics` notification with the errors in `uri`, which is
+	/// empty for closed documents.
+	fn diagnostics(&self, uri: &str) -> Value {
+		let diagnostics = match self.documents.get(uri) {
+			Some(text) => check(text).err().unwrap_or_default().into_iter().map(|(start, end, message)| json(&[
+				("range", range(text, start, end)),
+				("severity", Value::from(SEVERITY_ERROR)),
+				("source", Value::from("moses")),
+				("message", Value::from(message)),
+			])).collect(),
+			None => Vec::new(),
+		};
+		json(&[
+			("jsonrpc", Value::from("2.0")),
+			("method", Value::from("textDocument/publishDiagnostics")),
+			("params", json(&[("uri", Value::from(uri)), ("diagnostics", Value::Array(diagnostics))])),
+		])
+	}
+
+	/// The type of the whole document, since the syntax tree doesn't know
+	/// where its subexpressions are.
+	fn hover(&self, uri: &str) -> Value {
+		match self.documents.get(uri).map(|text| check(text)) {
+			Some(Ok(ty)) => json(&[
+				("contents", json(&[("kind", Value::from("plaintext")), ("value", Value::from(ty.to_string()))])),
+			]),
+			_ => Value::Null,
+		}
+	}
+}
+
+/// Parses and type checks `text`. Errors come with the byte range they
+/// concern, type errors concern the whole text.
+fn check(text: &str) -> Result<types::Type, Vec<(usize, usize, String)>> {
+	let (expr, errors) = recover::parse(text);
+	if !errors.is_empty() {
+		return Err(errors.into_iter().map(|err| (err.start, err.end, err.message)).collect());
+	}
+	types::type_of(&expr).map_err(|err| vec![(0, text.len(), err.message)])
+}
+
+/// Converts a byte range of `text` into an LSP range, whose positions count
+/// UTF-16 code units within a line.
+fn range(text: &str, start: usize, end: usize) -> Value {
+	json(&[("start", position(text, start)), ("end", position(text, end))])
+}
+
+fn position(text: &str, offset: usize) -> Value {
+	let before = &text[..offset];
+	let line = before.matches('\n').count();
+	let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+	let character = before[line_start..].encode_utf16().count();
+	json(&[("line", Value::from(line)), ("character", Value::from(character))])
+}
+
+fn json(fields: &[(&str, Value)]) -> Value {
+	Value::Object(fields.iter().map(|&(key, ref value)| (key.to_string(), value.clone())).collect())
+}
+
+/// Reads one message with its `Content-Length` header, or `None` at the end
+/// of the input.
+fn read_message<R: BufRead>(input: &mut R) -> io::Result<Option<Value>> {
+	let mut length = None;
+	loop {
+		let mut header = String::new();
+		if input.read_line(&mut header)? == 0 {
+			return Ok(None);
+		}
+		let header = header.trim_end();
+		if header.is_empty() {
+			break;
+		}
+		if let Some(value) = header.strip_prefix("Content-Length:") {
+			length = Some(value.trim().parse::<usize>()
+				.map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?);
+		}
+	}
+	let length = length.ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "missing Content-Length"))?;
+	let mut body = vec![0; length];
+	input.read_exact(&mut body)?;
+	serde_json::from_slice(&body).map(Some).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+}
+
+fn write_message<W: Write>(output: &mut W, message: &Value) -> io::Result<()> {
+	let body = message.to_string();
+	write!(output, "Content-Length: {}\r\n\r\n{}", body.len(), body)?;
+	output.flush()
+}
+
+#[test]
+fn scripted_session() {
+	let mut input = Vec::new();
+	for message in &[
+		r#"{"jsonrpc":"2.0","id":1,"method":"initialize","params":{}}"#,
+		r#"{"jsonrpc":"2.0","method":"initialized","params":{}}"#,
+		r#"{"jsonrpc":"2.0","method":"textDocument/didOpen","params":{"textDocument":{"uri":"file:///a.moses","languageId":"moses","version":1,"text":"1 +\n»ξ« + 2"}}}"#,
+		r#"{"jsonrpc":"2.0","method":"textDocument/didChange","params":{"textDocument":{"uri":"file:///a.moses","version":2},"contentChanges":[{"text":"(1 + 2"}]}}"#,
+		r#"{"jsonrpc":"2.0","method":"textDocument/didChange","params":{"textDocument":{"uri":"file:///a.moses","version":3},"contentChanges":[{"text":"1 + 3000000000"}]}}"#,
+		r#"{"jsonrpc":"2.0","method":"textDocument/didChange","params":{"textDocument":{"uri":"file:///a.moses","version":4},"contentChanges":[{"text":"1 < 2"}]}}"#,
+		r#"{"jsonrpc":"2.0","id":2,"method":"textDocument/hover","params":{"textDocument":{"uri":"file:///a.moses"},"position":{"line":0,"character":0}}}"#,
+		r#"{"jsonrpc":"2.0","id":3,"method":"textDocument/definition","params":{"textDocument":{"uri":"file:///a.moses"},"position":{"line":0,"character":0}}}"#,
+		r#"{"jsonrpc":"2.0","method":"$/setTrace","params":{"value":"off"}}"#,
+		r#"{"jsonrpc":"2.0","id":4,"method":"$/foo"}"#,
+		r#"{"jsonrpc":"2.0","id":5,"method":"shutdown"}"#,
+		r#"{"jsonrpc":"2.0","method":"exit"}"#,
+	] {
+		write!(input, "Content-Length: {}\r\n\r\n{}", message.len(), message).unwrap();
+	}
+	let mut output = Vec::new();
+	assert_eq!(serve(&mut &input[..], &mut output), 0);
+
+	let mut output = &output[..];
+	let mut replies = Vec::new();
+	while let Some(reply) = read_message(&mut output).unwrap() {
+		replies.push(reply);
+	}
+	assert_eq!(replies.len(), 9);
+	assert_eq!(replies[0]["result"]["capabilities"]["hoverProvider"], true);
+	assert_eq!(replies[1]["params"]["diagnostics"].as_array().unwrap().len(), 2);
+	let diagnostic = &replies[1]["params"]["diagnostics"][0];
+	assert_eq!(diagnostic["range"]["start"]["line"], 1);
+	assert_eq!(diagnostic["range"]["start"]["character"], 0);
+	assert_eq!(diagnostic["range"]["end"]["character"], 3);
+	assert!(diagnostic["message"].as_str().unwrap().starts_with("unexpected `»ξ«`"));
+	let diagnostic = &replies[2]["params"]["diagnostics"][0];
+	assert_eq!(diagnostic["range"]["start"]["character"], 6);
+	assert!(diagnostic["message"].as_str().unwrap().starts_with("unexpected end of input"));
+	let diagnostic = &replies[3]["params"]["diagnostics"][0];
+	assert_eq!(diagnostic["range"]["start"]["character"], 4);
+	assert_eq!(diagnostic["range"]["end"]["character"], 14);
+	assert_eq!(diagnostic["message"], "number too large");
+	assert_eq!(replies[4]["params"]["diagnostics"], Value::Array(Vec::new()));
+	assert_eq!(replies[5]["id"], 2);
+	assert_eq!(replies[5]["result"]["contents"]["value"], "Bool");
+	assert_eq!(replies[6]["error"]["code"], METHOD_NOT_FOUND);
+	assert_eq!(replies[7]["id"], 4);
+	assert_eq!(replies[7]["error"]["code"], METHOD_NOT_FOUND);
+	assert_eq!(replies[8]["id"], 5);
+	assert_eq!(replies[8]["result"], Value::Null);
+}
@@ -0,0 +1,292 @@
+use std::fmt::{self, Display, Formatter};
+use std::io::{self, Read, Write};
+use ast::{Expr, Opcode};
+use eval::Value;
+
+/// Every serialized module starts with these bytes.
+pub const MAGIC: &[u8; 4] = b"MBC\0";
+/// The version of the serialized module format, bumped with every
+/// incompatible change.
+pub const VERSION: u16 = 1;
+
+/// All opcodes in the order of their serialized numbers.
+const OPCODES: [Opcode; 13] = [
+	Opcode::Mul, Opcode::Div, Opcode::Add, Opcode::Sub,
+	Opcode::And, Opcode::Or, Opcode::Not,
+	Opcode::Eq, Opcode::Neq, Opcode::Greater, Opcode::Lesser, Opcode::Geq, Opcode::Leq,
+];
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Instruction {
+	/// Pushes the constant with the given index.
+	Constant(usize),
+	/// Pops the operands of the operator and pushes its result. `and` and
+	/// `or` are compiled to jumps instead.
+	Op(Opcode),
+	/// Jumps to the given position if the top of the stack is `False`,
+	/// leaving it there.
+	JumpIfFalse(usize),
+	/// Jumps to the given position if the top of the stack is `True`,
+	/// leaving it there.
+	JumpIfTrue(usize),
+	Pop,
+	/// Stops the program, its value is the top of the stack.
+	Return,
+}
+
+/// A compiled program.
+#[derive(Clone, PartialEq, Debug, Default)]
+pub struct Chunk {
+	pub constants: Vec<Value>,
+	pub code: Vec<Instruction>,
+}
+
+pub fn compile(expr: &Expr) -> Chunk {
+	let mut chunk = Chunk::default();
+	chunk.compile(expr);
+	chunk.code.push(Instruction::Return);
+	chunk
+}
+
+impl Chunk {
+	fn compile(&mut self, expr: &Expr) {
+		match *expr {
+			Expr::Number(n) => self.constant(Value::Number(n)),
+			Expr::True => self.constant(Value::Bool(true)),
+			Expr::False => self.constant(Value::Bool(false)),
+			Expr::Str(ref s) => self.constant(Value::Str(s.clone())),
+			Expr::Char(c) => self.constant(Value::Char(c)),
+			Expr::Error => unreachable!("syntax errors don't type check"),
+			Expr::Op(Opcode::And, ref operands) => self.short_circuit(Instruction::JumpIfFalse(0), operands),
+			Expr::Op(Opcode::Or, ref operands) => self.short_circuit(Instruction::JumpIfTrue(0), operands),
+			Expr::Op(op, ref operands) => {
+				for operand in operands {
+					self.compile(operand);
+				}
+				self.code.push(Instruction::Op(op));
+			}
+		}
+	}
+
+	fn constant(&mut self, value: Value) {
+		let index = match self.constants.iter().position(|constant| *constant == value) {
+			Some(index) => index,
+			None => {
+				self.constants.push(value);
+				self.constants.len() - 1
+			}
+		};
+		self.code.push(Instruction::Constant(index));
+	}
+
+	/// Compiles `fst and snd` or `fst or snd` so that `snd` is skipped if
+	/// `jump` decides the result already.
+	fn short_circuit(&mut self, jump: Instruction, operands: &[Box<Expr>]) {
+		self.compile(&operands[0]);
+		let jump_at = self.code.len();
+		self.code.push(jump);
+		self.code.push(Instruction::Pop);
+		self.compile(&operands[1]);
+		let end = self.code.len();
+		self.code[jump_at] = match jump {
+			Instruction::JumpIfFalse(_) => Instruction::JumpIfFalse(end),
+			_ => Instruction::JumpIfTrue(end),
+		};
+	}
+}
+
+// Serialized modules consist of the magic bytes, the version, the constants
+// and the code. Numbers are little endian, every constant and instruction
+// starts with a tag byte.
+impl Chunk {
+	pub fn write_to<W: Write>(&self, out: &mut W) -> io::Result<()> {
+		out.write_all(MAGIC)?;
+		out.write_all(&VERSION.to_le_bytes())?;
+		write_u32(out, self.constants.len())?;
+		for constant in &self.constants {
+			match *constant {
+				Value::Number(n) => {
+					out.write_all(&[0])?;
+					out.write_all(&n.to_le_bytes())?;
+				}
+				Value::Bool(b) => out.write_all(&[1, b as u8])?,
+				Value::Str(ref s) => {
+					out.write_all(&[2])?;
+					write_u32(out, s.len())?;
+					out.write_all(s.as_bytes())?;
+				}
+				Value::Char(c) => {
+					out.write_all(&[3])?;
+					write_u32(out, c as usize)?;
+				}
+			}
+		}
+		write_u32(out, self.code.len())?;
+		for instruction in &self.code {
+			match *instruction {
+				Instruction::Constant(index) => {
+					out.write_all(&[0])?;
+					write_u32(out, index)?;
+				}
+				Instruction::Op(op) => {
+					let number = OPCODES.iter().position(|&known| known == op).unwrap();
+					out.write_all(&[1, number as u8])?;
+				}
+				Instruction::JumpIfFalse(target) => {
+					out.write_all(&[2])?;
+					write_u32(out, target)?;
+				}
+				Instruction::JumpIfTrue(target) => {
+					out.write_all(&[3])?;
+					write_u32(out, target)?;
+				}
+				Instruction::Pop => out.write_all(&[4])?,
+				Instruction::Return => out.write_all(&[5])?,
+			}
+		}
+		Ok(())
+	}
+
+	pub fn read_from<R: Read>(input: &mut R) -> io::Result<Chunk> {
+		let mut magic = [0; 4];
+		input.read_exact(&mut magic)?;
+		if &magic != MAGIC {
+			return Err(invalid("not a Moses bytecode module".to_string()));
+		}
+		let mut version = [0; 2];
+		input.read_exact(&mut version)?;
+		let version = u16::from_le_bytes(version);
+		if version != VERSION {
+			return Err(invalid(format!("unsupported bytecode version {}", version)));
+		}
+		let mut chunk = Chunk::default();
+		for _ in 0..read_u32(input)? {
+			let constant = match read_u8(input)? {
+				0 => Value::Number(read_u32(input)? as i32),
+				1 => Value::Bool(read_u8(input)? != 0),
+				2 => {
+					// The length isn't trusted to allocate up front.
+					let length = read_u32(input)?;
+					let mut bytes = Vec::new();
+					if input.by_ref().take(u64::from(length)).read_to_end(&mut bytes)? < length as usize {
+						return Err(invalid(format!("string of {} bytes is cut off", length)));
+					}
+					Value::Str(String::from_utf8(bytes).map_err(|err| invalid(err.to_string()))?)
+				}
+				3 => {
+					let code = read_u32(input)?;
+					Value::Char(::std::char::from_u32(code)
+						.ok_or_else(|| invalid(format!("invalid character {:#x}", code)))?)
+				}
+				tag => return Err(invalid(format!("unknown constant tag {}", tag))),
+			};
+			chunk.constants.push(constant);
+		}
+		for _ in 0..read_u32(input)? {
+			let instruction = match read_u8(input)? {
+				0 => Instruction::Constant(read_u32(input)? as usize),
+				1 => {
+					let number = read_u8(input)?;
+					Instruction::Op(*OPCODES.get(number as usize)
+						.ok_or_else(|| invalid(format!("unknown opcode {}", number)))?)
+				}
+				2 => Instruction::JumpIfFalse(read_u32(input)? as usize),
+				3 => Instruction::JumpIfTrue(read_u32(input)? as usize),
+				4 => Instruction::Pop,
+				5 => Instruction::Return,
+				tag => return Err(invalid(format!("unknown instruction tag {}", tag))),
+			};
+			chunk.code.push(instruction);
+		}
+		chunk.validate()?;
+		Ok(chunk)
+	}
+
+	/// Rejects modules the VM would crash or loop on instead of panicking:
+	/// every constant has to exist, every operator needs an instruction,
+	/// jumps only go forward and the stack never runs empty, whichever way
+	/// the jumps go.
+	fn validate(&self) -> io::Result<()> {
+		if self.code.last() != Some(&Instruction::Return) {
+			return Err(invalid("code does not end with Return".to_string()));
+		}
+		let mut depth = 0;
+		let mut depth_at_target: Vec<Option<usize>> = vec![None; self.code.len()];
+		for (position, instruction) in self.code.iter().enumerate() {
+			if let Some(expected) = depth_at_target[position] {
+				if expected != depth {
+					return Err(invalid(format!("inconsistent stack at {}", position)));
+				}
+			}
+			let (needed, pushed) = match *instruction {
+				Instruction::Constant(index) if index >= self.constants.len() =>
+					return Err(invalid(format!("constant {} out of range", index))),
+				Instruction::Constant(_) => (0, 1),
+				Instruction::Op(Opcode::Not) => (1, 1),
+				// `and` and `or` are compiled to jumps, the VM has no
+				// instruction for them.
+				Instruction::Op(op @ Opcode::And) | Instruction::Op(op @ Opcode::Or) =>
+					return Err(invalid(format!("operator {:?} at {} is not an instruction", op, position))),
+				Instruction::Op(_) => (2, 1),
+				Instruction::JumpIfFalse(target) | Instruction::JumpIfTrue(target) => {
+					if target <= position || target >= self.code.len() {
+						return Err(invalid(format!("jump target {} out of range", target)));
+					}
+					if depth_at_target[target].is_some_and(|expected| expected != depth) {
+						return Err(invalid(format!("inconsistent stack at {}", target)));
+					}
+					depth_at_target[target] = Some(depth);
+					(1, 1)
+				}
+				Instruction::Pop => (1, 0),
+				Instruction::Return => (1, 1),
+			};
+			if depth < needed {
+				return Err(invalid(format!("stack underflow at {}", position)));
+			}
+			depth = depth - needed + pushed;
+		}
+		Ok(())
+	}
+}
+
+fn invalid(message: String) -> io::Error {
+	io::Error::new(io::ErrorKind::InvalidData, message)
+}
+
+fn write_u32<W: Write>(out: &mut W, n: usize) -> io::Result<()> {
+	out.write_all(&(n as u32).to_le_bytes())
+}
+
+fn read_u8<R: Read>(input: &mut R) -> io::Result<u8> {
+	let mut byte = [0];
+	input.read_exact(&mut byte)?;
+	Ok(byte[0])
+}
+
+fn read_u32<R: Read>(input: &mut R) -> io::Result<u32> {
+	let mut bytes = [0; 4];
+	input.read_exact(&mut bytes)?;
+	Ok(u32::from_le_bytes(bytes))
+}
+
+/// Disassembles the chunk.
+impl Display for Chunk {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		writeln!(fmt, "moses bytecode version {}", VERSION)?;
+		writeln!(fmt, "constants:")?;
+		for (index, constant) in self.constants.iter().enumerate() {
+			writeln!(fmt, "{:>6}  {}", index, constant)?;
+		}
+		writeln!(fmt, "code:")?;
+		for (position, instruction) in self.code.iter().enumerate() {
+			match *instruction {
+				Instruction::Constant(index) => writeln!(
+					fmt, "{:>6}  Constant {}  # {}", position, index, self.constants[index])?,
+				Instruction::Op(op) => writeln!(fmt, "{:>6}  Op {:?}", position, op)?,
+				_ => writeln!(fmt, "{:>6}  {:?}", position, instruction)?,
+			}
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,159 @@
+use std::fmt::Write;
+use ast::{Expr, Opcode};
+use types::Type;
+
+/// The functions every generated program starts with. Numbers are checked
+/// like in the evaluator and panics are reported on stderr, as the section
+/// “Panics” of the specification suggests for compiled programs.
+const RUNTIME: &str = r#"#include <stdint.h>
+#include <stdio.h>
+#include <stdlib.h>
+
+static void moses_panic(const char *message, const char *op, int32_t fst, int32_t snd) {
+	fprintf(stderr, "panic: %s in %s[%ld, %ld]\n", message, op, (long) fst, (long) snd);
+	exit(1);
+}
+
+static int32_t moses_arithmetic(int64_t result, const char *op, int32_t fst, int32_t snd) {
+	if (result < INT32_MIN || result > INT32_MAX) {
+		moses_panic("arithmetic overflow", op, fst, snd);
+	}
+	return (int32_t) result;
+}
+
+static int32_t moses_add(int32_t fst, int32_t snd) {
+	return moses_arithmetic((int64_t) fst + snd, "Add", fst, snd);
+}
+
+static int32_t moses_sub(int32_t fst, int32_t snd) {
+	return moses_arithmetic((int64_t) fst - snd, "Sub", fst, snd);
+}
+
+static int32_t moses_mul(int32_t fst, int32_t snd) {
+	return moses_arithmetic((int64_t) fst * snd, "Mul", fst, snd);
+}
+
+static int32_t moses_div(int32_t fst, int32_t snd) {
+	if (snd == 0) {
+		moses_panic("division by zero", "Div", fst, snd);
+	}
+	return moses_arithmetic((int64_t) fst / snd, "Div", fst, snd);
+}
+
+static void moses_print_number(int32_t n) {
+	printf("%ld\n", (long) n);
+}
+
+static void moses_print_bool(int b) {
+	printf("%s\n", b ? "True" : "False");
+}
+
+/* Strings and characters are kept as UTF-8 encoded C strings. */
+static void moses_print_str(const char *s) {
+	printf("\xc2\xbb%s\xc2\xab\n", s);
+}
+
+static void moses_print_char(const char *c) {
+	printf("\xe2\x80\xba%s\xe2\x80\xb9\n", c);
+}
+"#;
+
+/// Translates a type checked expression of type `ty` into a C program that
+/// prints its value.
+pub fn compile(expr: &Expr, ty: Type) -> String {
+	let mut generator = Generator { body: String::new(), temporaries: 0, indent: 1 };
+	let value = generator.expression(expr);
+	let print = match ty {
+		Type::Number => "moses_print_number",
+		Type::Bool => "moses_print_bool",
+		Type::Str => "moses_print_str",
+		Type::Char => "moses_print_char",
+	};
+	format!("{}\nint main(void) {{\n{}\t{}({});\n\treturn 0;\n}}\n", RUNTIME, generator.body, print, value)
+}
+
+/// Emits one statement per operation, storing each intermediate result in a
+/// temporary. That way, operands are evaluated from left to right like in
+/// the evaluator, which C doesn't guarantee for function arguments.
+struct Generator {
+	body: String,
+	temporaries: usize,
+	indent: usize,
+}
+
+impl Generator {
+	/// Emits the statements computing `expr` and returns a C expression for
+	/// its value.
+	fn expression(&mut self, expr: &Expr) -> String {
+		match *expr {
+			Expr::Number(n) => format!("INT32_C({})", n),
+			Expr::True => "1".to_string(),
+			Expr::False => "0".to_string(),
+			Expr::Str(ref s) => string_literal(s),
+			Expr::Char(c) => string_literal(&c.to_string()),
+			Expr::Error => unreachable!("syntax errors don't type check"),
+			Expr::Op(Opcode::Not, ref operands) => {
+				let operand = self.expression(&operands[0]);
+				self.temporary("int", format!("!{}", operand))
+			}
+			Expr::Op(op @ Opcode::And, ref operands) | Expr::Op(op @ Opcode::Or, ref operands) => {
+				let fst = self.expression(&operands[0]);
+				let result = self.temporary("int", fst);
+				self.line(&format!("if ({}{}) {{", if op == Opcode::And { "" } else { "!" }, result));
+				self.indent += 1;
+				let snd = self.expression(&operands[1]);
+				self.line(&format!("{} = {};", result, snd));
+				self.indent -= 1;
+				self.line("}");
+				result
+			}
+			Expr::Op(op, ref operands) => {
+				let fst = self.expression(&operands[0]);
+				let snd = self.expression(&operands[1]);
+				let (ty, value) = match op {
+					Opcode::Add => ("int32_t", format!("moses_add({}, {})", fst, snd)),
+					Opcode::Sub => ("int32_t", format!("moses_sub({}, {})", fst, snd)),
+					Opcode::Mul => ("int32_t", format!("moses_mul({}, {})", fst, snd)),
+					Opcode::Div => ("int32_t", format!("moses_div({}, {})", fst, snd)),
+					Opcode::Eq => ("int", format!("{} == {}", fst, snd)),
+					Opcode::Neq => ("int", format!("{} != {}", fst, snd)),
+					Opcode::Greater => ("int", format!("{} > {}", fst, snd)),
+					Opcode::Lesser => ("int", format!("{} < {}", fst, snd)),
+					Opcode::Geq => ("int", format!("{} >= {}", fst, snd)),
+					Opcode::Leq => ("int", format!("{} <= {}", fst, snd)),
+					Opcode::Not | Opcode::And | Opcode::Or => unreachable!(),
+				};
+				self.temporary(ty, value)
+			}
+		}
+	}
+
+	fn temporary(&mut self, ty: &str, value: String) -> String {
+		let name = format!("t{}", self.temporaries);
+		self.temporaries += 1;
+		self.line(&format!("{} {} = {};", ty, name, value));
+		name
+	}
+
+	fn line(&mut self, line: &str) {
+		for _ in 0..self.indent {
+			self.body.push('\t');
+		}
+		self.body.push_str(line);
+		self.body.push('\n');
+	}
+}
+
+/// Writes `s` as a C string literal, escaping everything but printable ASCII.
+fn string_literal(s: &str) -> String {
+	let mut out = String::from("\"");
+	for byte in s.bytes() {
+		match byte {
+			b'"' | b'\\' | b'?' => write!(out, "\\{}", byte as char).unwrap(),
+			0x20..=0x7e => out.push(byte as char),
+			_ => write!(out, "\\{:03o}", byte).unwrap(),
+		}
+	}
+	out.push('"');
+	out
+}
@@ -0,0 +1,260 @@
+use std::env;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Write, IsTerminal};
+use std::path::Path;
+use std::process::{self, Command};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use moses::{Interpreter, Error, recover};
+use moses::format::{self, Spelling};
+use moses::bytecode::Chunk;
+use moses::vm;
+use repl;
+
+/// The program ran and its value was printed.
+pub const EXIT_SUCCESS: i32 = 0;
+/// The program panicked.
+pub const EXIT_PANIC: i32 = 1;
+/// The program could not be read, parsed or type checked.
+pub const EXIT_ERROR: i32 = 2;
+
+const USAGE: &str = "\
+usage: moses                 start the interactive interpreter
+       moses run FILE        evaluate FILE and print its value
+       moses check FILE      parse and type check FILE
+       moses -e EXPR         evaluate EXPR and print its value
+       moses fmt [--unicode] FILE
+                             print FILE formatted, with operators like `≤`
+                             instead of `<=` if --unicode is given
+       moses compile FILE [-o OUT]
+                             compile FILE to a bytecode module, by default
+                             FILE with the extension .mbc
+       moses disasm MODULE   print the contents of a bytecode module
+       moses wat FILE        print FILE as a WebAssembly text module for
+                             WASI engines
+       moses build FILE [-o OUT]
+                             compile FILE to a native executable with the C
+                             compiler from $CC, by default FILE without
+                             extension, or FILE.out if it has none
+
+`moses run` executes bytecode modules, too. FILE can be `-` for stdin.
+Without arguments, a program piped into stdin is evaluated instead of
+starting the interpreter.";
+
+/// Runs the command line `args` (without the program name) and returns the
+/// exit status.
+pub fn run(args: &[String]) -> i32 {
+	let args: Vec<&str> = args.iter().map(|arg| &arg[..]).collect();
+	match &args[..] {
+		[] if io::stdin().is_terminal() => {
+			repl::run();
+			EXIT_SUCCESS
+		}
+		[] => read_source("-").map_or(EXIT_ERROR, |source| execute(&source)),
+		["run", file] if file.ends_with(".mbc") => read_module(file).map_or(EXIT_ERROR, |chunk| run_module(&chunk)),
+		["run", file] => read_source(file).map_or(EXIT_ERROR, |source| execute(&source)),
+		["check", file] => read_source(file).map_or(EXIT_ERROR, |source| check(&source)),
+		["-e", source] => execute(source),
+		["fmt", file] => read_source(file).map_or(EXIT_ERROR, |source| fmt(&source, Spelling::Ascii)),
+		["fmt", "--unicode", file] => read_source(file).map_or(EXIT_ERROR, |source| fmt(&source, Spelling::Unicode)),
+		["compile", file] => {
+			let out = Path::new(file).with_extension("mbc");
+			compile(file, &out.to_string_lossy())
+		}
+		["compile", file, "-o", out] => compile(file, out),
+		["build", file] => {
+			let out = match Path::new(file).extension() {
+				Some(_) => Path::new(file).with_extension(""),
+				None => Path::new(file).with_extension("out"),
+			};
+			build(file, &out.to_string_lossy())
+		}
+		["build", file, "-o", out] => build(file, out),
+		["wat", file] => read_source(file).map_or(EXIT_ERROR, |source| {
+			match Interpreter::new().compile_wat_str(&source) {
+				Ok(module) => {
+					print!("{}", module);
+					EXIT_SUCCESS
+				}
+				Err(err) => {
+					eprintln!("{}", err);
+					EXIT_ERROR
+				}
+			}
+		}),
+		["disasm", file] => read_module(file).map_or(EXIT_ERROR, |chunk| {
+			print!("{}", chunk);
+			EXIT_SUCCESS
+		}),
+		["-h"] | ["--help"] => {
+			println!("{}", USAGE);
+			EXIT_SUCCESS
+		}
+		_ => {
+			eprintln!("{}", USAGE);
+			EXIT_ERROR
+		}
+	}
+}
+
+fn read_source(file: &str) -> Option<String> {
+	let mut source = String::new();
+	let read = if file == "-" {
+		io::stdin().read_to_string(&mut source)
+	} else {
+		File::open(file).and_then(|mut file| file.read_to_string(&mut source))
+	};
+	match read {
+		Ok(_) => Some(source),
+		Err(err) => {
+			eprintln!("error: could not read {}: {}", file, err);
+			None
+		}
+	}
+}
+
+fn fmt(source: &str, spelling: Spelling) -> i32 {
+	let (expr, errors) = recover::parse(source);
+	if !errors.is_empty() {
+		eprintln!("{}", Error::Parse(errors));
+		return EXIT_ERROR;
+	}
+	println!("{}", format::format(&expr, spelling));
+	EXIT_SUCCESS
+}
+
+fn read_module(file: &str) -> Option<Chunk> {
+	match File::open(file).and_then(|mut file| Chunk::read_from(&mut file)) {
+		Ok(chunk) => Some(chunk),
+		Err(err) => {
+			eprintln!("error: could not read {}: {}", file, err);
+			None
+		}
+	}
+}
+
+/// Whether writing `out` would replace the source `file`, which is reported.
+fn overwrites_source(file: &str, out: &str) -> bool {
+	let same_file = match (fs::canonicalize(file), fs::canonicalize(out)) {
+		(Ok(file), Ok(out)) => file == out,
+		_ => Path::new(file) == Path::new(out),
+	};
+	if same_file {
+		eprintln!("error: {} would overwrite the source file", out);
+	}
+	same_file
+}
+
+fn compile(file: &str, out: &str) -> i32 {
+	if overwrites_source(file, out) {
+		return EXIT_ERROR;
+	}
+	let source = match read_source(file) {
+		Some(source) => source,
+		None => return EXIT_ERROR,
+	};
+	let chunk = match Interpreter::new().compile_str(&source) {
+		Ok(chunk) => chunk,
+		Err(err) => {
+			eprintln!("{}", err);
+			return EXIT_ERROR;
+		}
+	};
+	match File::create(out).and_then(|mut out| chunk.write_to(&mut out)) {
+		Ok(()) => EXIT_SUCCESS,
+		Err(err) => {
+			eprintln!("error: could not write {}: {}", out, err);
+			EXIT_ERROR
+		}
+	}
+}
+
+fn build(file: &str, out: &str) -> i32 {
+	if overwrites_source(file, out) {
+		return EXIT_ERROR;
+	}
+	let source = match read_source(file) {
+		Some(source) => source,
+		None => return EXIT_ERROR,
+	};
+	let program = match Interpreter::new().compile_c_str(&source) {
+		Ok(program) => program,
+		Err(err) => {
+			eprintln!("{}", err);
+			return EXIT_ERROR;
+		}
+	};
+	// The C file goes into a fresh directory only we can access, so nobody
+	// can plant a symlink where it's written.
+	let dir = env::temp_dir().join(format!("moses-{}-{}", process::id(), timestamp()));
+	let mut builder = fs::DirBuilder::new();
+	#[cfg(unix)]
+	std::os::unix::fs::DirBuilderExt::mode(&mut builder, 0o700);
+	if let Err(err) = builder.create(&dir) {
+		eprintln!("error: could not create {}: {}", dir.display(), err);
+		return EXIT_ERROR;
+	}
+	let c_file = dir.join("program.c");
+	let written = OpenOptions::new().write(true).create_new(true).open(&c_file)
+		.and_then(|mut c_file| c_file.write_all(program.as_bytes()));
+	if let Err(err) = written {
+		eprintln!("error: could not write {}: {}", c_file.display(), err);
+		let _ = fs::remove_dir_all(&dir);
+		return EXIT_ERROR;
+	}
+	let compiler = env::var("CC").unwrap_or_else(|_| "cc".to_string());
+	let status = Command::new(&compiler).arg("-std=c99").arg("-O2").arg("-o").arg(out).arg(&c_file).status();
+	let _ = fs::remove_dir_all(&dir);
+	match status {
+		Ok(ref status) if status.success() => EXIT_SUCCESS,
+		Ok(_) => EXIT_ERROR,
+		Err(err) => {
+			eprintln!("error: could not run {}: {}", compiler, err);
+			EXIT_ERROR
+		}
+	}
+}
+
+/// The nanoseconds of the current time, to make temporary names hard to guess.
+fn timestamp() -> u32 {
+	SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |since| since.subsec_nanos())
+}
+
+fn run_module(chunk: &Chunk) -> i32 {
+	match vm::run(chunk) {
+		Ok(value) => {
+			println!("{}", value);
+			EXIT_SUCCESS
+		}
+		Err(panic) => {
+			eprintln!("{}", panic);
+			EXIT_PANIC
+		}
+	}
+}
+
+fn check(source: &str) -> i32 {
+	match Interpreter::new().check_str(source) {
+		Ok(_) => EXIT_SUCCESS,
+		Err(err) => {
+			eprintln!("{}", err);
+			EXIT_ERROR
+		}
+	}
+}
+
+fn execute(source: &str) -> i32 {
+	match Interpreter::new().eval_str(source) {
+		Ok(value) => {
+			println!("{}", value);
+			EXIT_SUCCESS
+		}
+		Err(err) => {
+			eprintln!("{}", err);
+			match err {
+				Error::Panic(_) => EXIT_PANIC,
+				_ => EXIT_ERROR,
+			}
+		}
+	}
+}
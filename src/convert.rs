@@ -0,0 +1,111 @@
+use std::convert::TryFrom;
+use eval::{Value, MosesPanic, PanicKind};
+
+/// Conversion of a Rust value into a Moses value.
+pub trait IntoMoses {
+	fn into_moses(self) -> Value;
+}
+
+/// Conversion of a Moses value into a Rust value, panicking (the Moses way)
+/// if the value doesn't fit.
+pub trait FromMoses: Sized {
+	fn from_moses(value: Value) -> Result<Self, MosesPanic>;
+}
+
+impl IntoMoses for Value {
+	fn into_moses(self) -> Value {
+		self
+	}
+}
+
+impl FromMoses for Value {
+	fn from_moses(value: Value) -> Result<Value, MosesPanic> {
+		Ok(value)
+	}
+}
+
+fn conversion_failed(value: Value, target: &str) -> MosesPanic {
+	MosesPanic::new(
+		PanicKind::Conversion,
+		format!("cannot convert {} to {}", value, target))
+}
+
+// Only integers that always fit into a Moses number can be converted into
+// one, but all of them can be taken out of one.
+macro_rules! into_number {
+	($($ty:ty),*) => {$(
+		impl IntoMoses for $ty {
+			fn into_moses(self) -> Value {
+				Value::Number(i32::from(self))
+			}
+		}
+	)*}
+}
+
+macro_rules! from_number {
+	($($ty:ty),*) => {$(
+		impl FromMoses for $ty {
+			fn from_moses(value: Value) -> Result<$ty, MosesPanic> {
+				match value {
+					Value::Number(n) => <$ty>::try_from(n)
+						.map_err(|_| conversion_failed(value, stringify!($ty))),
+					_ => Err(conversion_failed(value, stringify!($ty))),
+				}
+			}
+		}
+	)*}
+}
+
+into_number!(i8, i16, i32, u8, u16);
+from_number!(i8, i16, i32, i64, isize, u8, u16, u32, u64, usize);
+
+impl IntoMoses for bool {
+	fn into_moses(self) -> Value {
+		Value::Bool(self)
+	}
+}
+
+impl FromMoses for bool {
+	fn from_moses(value: Value) -> Result<bool, MosesPanic> {
+		match value {
+			Value::Bool(b) => Ok(b),
+			_ => Err(conversion_failed(value, "bool")),
+		}
+	}
+}
+
+impl IntoMoses for char {
+	fn into_moses(self) -> Value {
+		Value::Char(self)
+	}
+}
+
+impl FromMoses for char {
+	fn from_moses(value: Value) -> Result<char, MosesPanic> {
+		match value {
+			Value::Char(c) => Ok(c),
+			_ => Err(conversion_failed(value, "char")),
+		}
+	}
+}
+
+impl IntoMoses for String {
+	fn into_moses(self) -> Value {
+		Value::Str(self)
+	}
+}
+
+impl IntoMoses for &str {
+	fn into_moses(self) -> Value {
+		Value::Str(self.to_string())
+	}
+}
+
+impl FromMoses for String {
+	fn from_moses(value: Value) -> Result<String, MosesPanic> {
+		match value {
+			Value::Str(s) => Ok(s),
+			_ => Err(conversion_failed(value, "String")),
+		}
+	}
+}
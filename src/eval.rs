@@ -0,0 +1,213 @@
+use std::fmt::{Display, Formatter, Error};
+use std::time::{Duration, Instant};
+use ast::{Expr, Opcode, MAX_DEPTH};
+
+#[derive(Clone, PartialEq, Debug)]
+pub enum Value {
+	Number(i32),
+	Bool(bool),
+	Str(String),
+	Char(char),
+}
+
+impl Display for Value {
+	fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+		use self::Value::*;
+		match *self {
+			Number(n) => write!(fmt, "{}", n),
+			Bool(true) => write!(fmt, "True"),
+			Bool(false) => write!(fmt, "False"),
+			Str(ref s) => write!(fmt, "»{}«", s),
+			Char(c) => write!(fmt, "›{}‹", c),
+		}
+	}
+}
+
+/// The reason a Moses program panicked.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum PanicKind {
+	DivisionByZero,
+	Overflow,
+	TypeMismatch,
+	Conversion,
+	OutOfFuel,
+	DepthLimit,
+	HeapLimit,
+	Timeout,
+	/// The program contains source that didn't parse, see `ast::Expr::Error`.
+	SyntaxError,
+}
+
+/// An unrecoverable error of a Moses program, see the section “Panics” of the
+/// specification.
+#[derive(Clone, PartialEq, Debug)]
+pub struct MosesPanic {
+	pub kind: PanicKind,
+	pub message: String,
+}
+
+impl MosesPanic {
+	pub fn new(kind: PanicKind, message: String) -> MosesPanic {
+		MosesPanic { kind, message }
+	}
+}
+
+impl Display for MosesPanic {
+	fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+		write!(fmt, "panic: {}", self.message)
+	}
+}
+
+/// Bounds on the resources a program may use, for running untrusted code.
+/// `None` means unlimited.
+#[derive(Clone, Default, Debug)]
+pub struct Limits {
+	/// The number of expressions that may be evaluated.
+	pub fuel: Option<u64>,
+	/// How deeply expressions may be nested, never more than
+	/// `ast::MAX_DEPTH`.
+	pub max_depth: Option<usize>,
+	/// The number of bytes of string data that may be allocated.
+	pub max_heap: Option<usize>,
+	pub timeout: Option<Duration>,
+}
+
+pub fn eval(expr: &Expr) -> Result<Value, MosesPanic> {
+	eval_limited(expr, &Limits::default())
+}
+
+pub fn eval_limited(expr: &Expr, limits: &Limits) -> Result<Value, MosesPanic> {
+	let mut evaluator = Evaluator {
+		limits,
+		steps: 0,
+		depth: 0,
+		heap: 0,
+		deadline: limits.timeout.map(|timeout| Instant::now() + timeout),
+	};
+	evaluator.eval(expr)
+}
+
+struct Evaluator<'a> {
+	limits: &'a Limits,
+	steps: u64,
+	depth: usize,
+	heap: usize,
+	deadline: Option<Instant>,
+}
+
+impl<'a> Evaluator<'a> {
+	fn eval(&mut self, expr: &Expr) -> Result<Value, MosesPanic> {
+		self.step()?;
+		self.depth += 1;
+		let value = self.eval_expr(expr);
+		self.depth -= 1;
+		value
+	}
+
+	fn step(&mut self) -> Result<(), MosesPanic> {
+		self.steps += 1;
+		if self.limits.fuel.is_some_and(|fuel| self.steps > fuel) {
+			return Err(MosesPanic::new(
+				PanicKind::OutOfFuel,
+				format!("ran out of fuel after {} steps", self.steps - 1)));
+		}
+		if self.depth >= self.limits.max_depth.map_or(MAX_DEPTH, |max_depth| max_depth.min(MAX_DEPTH)) {
+			return Err(MosesPanic::new(
+				PanicKind::DepthLimit,
+				format!("expressions nested deeper than {}", self.depth)));
+		}
+		if self.deadline.is_some_and(|deadline| Instant::now() >= deadline) {
+			return Err(MosesPanic::new(
+				PanicKind::Timeout,
+				format!("timed out after {:?}", self.limits.timeout.unwrap())));
+		}
+		Ok(())
+	}
+
+	fn allocate(&mut self, bytes: usize) -> Result<(), MosesPanic> {
+		self.heap += bytes;
+		match self.limits.max_heap {
+			Some(max_heap) if self.heap > max_heap => Err(MosesPanic::new(
+				PanicKind::HeapLimit,
+				format!("allocated more than {} bytes", max_heap))),
+			_ => Ok(()),
+		}
+	}
+
+	fn eval_expr(&mut self, expr: &Expr) -> Result<Value, MosesPanic> {
+		match *expr {
+			Expr::Number(n) => Ok(Value::Number(n)),
+			Expr::True => Ok(Value::Bool(true)),
+			Expr::False => Ok(Value::Bool(false)),
+			Expr::Str(ref s) => {
+				self.allocate(s.len())?;
+				Ok(Value::Str(s.clone()))
+			}
+			Expr::Char(c) => Ok(Value::Char(c)),
+			Expr::Op(op, ref operands) => self.eval_op(op, operands),
+			Expr::Error => Err(MosesPanic::new(PanicKind::SyntaxError, "syntax error".to_string())),
+		}
+	}
+
+	fn eval_op(&mut self, op: Opcode, operands: &[Box<Expr>]) -> Result<Value, MosesPanic> {
+		use ast::Opcode::*;
+		match op {
+			Not => Ok(Value::Bool(!self.bool_operand(op, &operands[0])?)),
+			// `and` and `or` only evaluate their second operand if needed.
+			And => Ok(Value::Bool(self.bool_operand(op, &operands[0])? && self.bool_operand(op, &operands[1])?)),
+			Or => Ok(Value::Bool(self.bool_operand(op, &operands[0])? || self.bool_operand(op, &operands[1])?)),
+			_ => {
+				let fst = self.number_operand(op, &operands[0])?;
+				let snd = self.number_operand(op, &operands[1])?;
+				apply_numeric(op, fst, snd)
+			}
+		}
+	}
+
+	fn bool_operand(&mut self, op: Opcode, operand: &Expr) -> Result<bool, MosesPanic> {
+		match self.eval(operand)? {
+			Value::Bool(b) => Ok(b),
+			other => Err(type_mismatch(op, "a boolean", other)),
+		}
+	}
+
+	fn number_operand(&mut self, op: Opcode, operand: &Expr) -> Result<i32, MosesPanic> {
+		match self.eval(operand)? {
+			Value::Number(n) => Ok(n),
+			other => Err(type_mismatch(op, "a number", other)),
+		}
+	}
+}
+
+/// Applies an arithmetic or comparison operator to two numbers.
+pub fn apply_numeric(op: Opcode, fst: i32, snd: i32) -> Result<Value, MosesPanic> {
+	use ast::Opcode::*;
+	match op {
+		Add => arithmetic(fst.checked_add(snd), op, fst, snd),
+		Sub => arithmetic(fst.checked_sub(snd), op, fst, snd),
+		Mul => arithmetic(fst.checked_mul(snd), op, fst, snd),
+		Div if snd == 0 => Err(MosesPanic::new(
+			PanicKind::DivisionByZero,
+			format!("division by zero in {:?}[{}, {}]", op, fst, snd))),
+		Div => arithmetic(fst.checked_div(snd), op, fst, snd),
+		Eq => Ok(Value::Bool(fst == snd)),
+		Neq => Ok(Value::Bool(fst != snd)),
+		Greater => Ok(Value::Bool(fst > snd)),
+		Lesser => Ok(Value::Bool(fst < snd)),
+		Geq => Ok(Value::Bool(fst >= snd)),
+		Leq => Ok(Value::Bool(fst <= snd)),
+		And | Or | Not => unreachable!("{:?} is not a numeric operator", op),
+	}
+}
+
+fn arithmetic(result: Option<i32>, op: Opcode, fst: i32, snd: i32) -> Result<Value, MosesPanic> {
+	result.map(Value::Number).ok_or_else(|| MosesPanic::new(
+		PanicKind::Overflow,
+		format!("arithmetic overflow in {:?}[{}, {}]", op, fst, snd)))
+}
+
+pub fn type_mismatch(op: Opcode, expected: &str, found: Value) -> MosesPanic {
+	MosesPanic::new(
+		PanicKind::TypeMismatch,
+		format!("{:?} expects {}, found {}", op, expected, found))
+}
@@ -0,0 +1,118 @@
+use ast::{Expr, Opcode};
+
+/// How operators with two spellings are written.
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Spelling {
+	/// `<=`, `>=`, `!=`, `*` and `/`
+	Ascii,
+	/// `≤`, `≥`, `≠`, `·` and `÷`
+	Unicode,
+}
+
+/// Prints `expr` as canonical Moses source: one space around binary
+/// operators and only the parentheses the grammar needs. The source parses
+/// back to `expr`, unless it contains a negative number, which only constant
+/// folding produces, or a syntax error, which is printed as `￼`. Comments
+/// don't make it into the syntax tree, so they are lost.
+pub fn format(expr: &Expr, spelling: Spelling) -> String {
+	let mut out = String::new();
+	write_expr(&mut out, expr, spelling);
+	out
+}
+
+/// How tightly an expression binds, following the tiers in parser.lalrpop.
+fn precedence(expr: &Expr) -> u8 {
+	use ast::Opcode::*;
+	match *expr {
+		Expr::Op(Or, _) => 1,
+		Expr::Op(And, _) => 2,
+		Expr::Op(Not, _) => 3,
+		Expr::Op(Eq, _) | Expr::Op(Neq, _) | Expr::Op(Greater, _)
+			| Expr::Op(Lesser, _) | Expr::Op(Geq, _) | Expr::Op(Leq, _) => 4,
+		Expr::Op(Add, _) | Expr::Op(Sub, _) => 5,
+		Expr::Op(Mul, _) | Expr::Op(Div, _) => 6,
+		_ => 7,
+	}
+}
+
+fn operator(op: Opcode, spelling: Spelling) -> &'static str {
+	use ast::Opcode::*;
+	let unicode = spelling == Spelling::Unicode;
+	match op {
+		Mul if unicode => "·",
+		Mul => "*",
+		Div if unicode => "÷",
+		Div => "/",
+		Add => "+",
+		Sub => "-",
+		And => "and",
+		Or => "or",
+		Not => "not",
+		Eq => "==",
+		Neq if unicode => "≠",
+		Neq => "!=",
+		Greater => ">",
+		Lesser => "<",
+		Geq if unicode => "≥",
+		Geq => ">=",
+		Leq if unicode => "≤",
+		Leq => "<=",
+	}
+}
+
+fn write_expr(out: &mut String, expr: &Expr, spelling: Spelling) {
+	match *expr {
+		Expr::Number(n) => out.push_str(&n.to_string()),
+		Expr::True => out.push_str("True"),
+		Expr::False => out.push_str("False"),
+		Expr::Error => out.push('￼'),
+		Expr::Str(ref s) => {
+			// Literals can't escape their closing delimiter, so use the other
+			// spelling if the preferred one can't hold the string.
+			let (open, close) = match spelling {
+				Spelling::Unicode if !s.contains('«') => ('»', '«'),
+				Spelling::Ascii if s.contains('"') => ('»', '«'),
+				_ => ('"', '"'),
+			};
+			out.push(open);
+			out.push_str(s);
+			out.push(close);
+		}
+		Expr::Char(c) => {
+			let (open, close) = match spelling {
+				Spelling::Unicode if c != '‹' => ('›', '‹'),
+				Spelling::Ascii if c == '\'' => ('›', '‹'),
+				_ => ('\'', '\''),
+			};
+			out.push(open);
+			out.push(c);
+			out.push(close);
+		}
+		Expr::Op(Opcode::Not, ref operands) => {
+			out.push_str("not ");
+			write_operand(out, &operands[0], precedence(expr) + 1, spelling);
+		}
+		Expr::Op(op, ref operands) => {
+			let own = precedence(expr);
+			// Operator tiers are left associative, except for comparisons,
+			// which don't nest at all.
+			let left = if own == 4 { own + 1 } else { own };
+			write_operand(out, &operands[0], left, spelling);
+			out.push(' ');
+			out.push_str(operator(op, spelling));
+			out.push(' ');
+			write_operand(out, &operands[1], own + 1, spelling);
+		}
+	}
+}
+
+/// Writes `operand`, in parentheses if it binds less tightly than `needed`.
+fn write_operand(out: &mut String, operand: &Expr, needed: u8, spelling: Spelling) {
+	if precedence(operand) < needed {
+		out.push('(');
+		write_expr(out, operand, spelling);
+		out.push(')');
+	} else {
+		write_expr(out, operand, spelling);
+	}
+}
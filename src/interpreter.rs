@@ -0,0 +1,121 @@
+use std::fmt::{self, Display, Formatter};
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::Path;
+
+use ast::Expr;
+use bytecode::{self, Chunk};
+use c;
+use optimize;
+use wat;
+use recover::{self, SyntaxError};
+use eval::{self, Value, MosesPanic, PanicKind, Limits};
+use types::{self, Type, TypeError};
+
+/// Everything that can go wrong when running a Moses program.
+#[derive(Debug)]
+pub enum Error {
+	Io(io::Error),
+	Parse(Vec<SyntaxError>),
+	Type(TypeError),
+	Panic(MosesPanic),
+}
+
+impl Display for Error {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		match *self {
+			Error::Io(ref err) => write!(fmt, "error: {}", err),
+			Error::Parse(ref errors) => {
+				for (i, err) in errors.iter().enumerate() {
+					if i > 0 {
+						writeln!(fmt)?;
+					}
+					write!(fmt, "parse error: {}", err)?;
+				}
+				Ok(())
+			}
+			Error::Type(ref err) => write!(fmt, "{}", err),
+			Error::Panic(ref panic) => write!(fmt, "{}", panic),
+		}
+	}
+}
+
+/// The entry point for embedding Moses.
+///
+/// ```
+/// let interpreter = moses::Interpreter::new();
+/// assert_eq!(interpreter.eval_str("2 * 3").unwrap(), moses::Value::Number(6));
+/// ```
+#[derive(Default)]
+pub struct Interpreter {
+	limits: Limits,
+}
+
+impl Interpreter {
+	pub fn new() -> Interpreter {
+		Interpreter::default()
+	}
+
+	/// An interpreter for untrusted programs, which panic once they exceed
+	/// the given `limits`.
+	pub fn with_limits(limits: Limits) -> Interpreter {
+		Interpreter { limits }
+	}
+
+	/// Parses and type checks `source` without evaluating it.
+	pub fn check_str(&self, source: &str) -> Result<Type, Error> {
+		self.parse(source).map(|(_, ty)| ty)
+	}
+
+	pub fn eval_str(&self, source: &str) -> Result<Value, Error> {
+		let (expr, _) = self.parse(source)?;
+		eval::eval_limited(&expr, &self.limits).map_err(Error::Panic)
+	}
+
+	pub fn eval_file<P: AsRef<Path>>(&self, path: P) -> Result<Value, Error> {
+		let mut source = String::new();
+		File::open(path)
+			.and_then(|mut file| file.read_to_string(&mut source))
+			.map_err(Error::Io)?;
+		self.eval_str(&source)
+	}
+
+	/// Parses, type checks and compiles `source` to bytecode. The compilers
+	/// fold constants first, see `optimize::fold`.
+	pub fn compile_str(&self, source: &str) -> Result<Chunk, Error> {
+		let (expr, _) = self.parse(source)?;
+		Ok(bytecode::compile(&optimize::fold(*expr)))
+	}
+
+	/// Parses, type checks and translates `source` to a C program printing
+	/// its value.
+	pub fn compile_c_str(&self, source: &str) -> Result<String, Error> {
+		let (expr, ty) = self.parse(source)?;
+		Ok(c::compile(&optimize::fold(*expr), ty))
+	}
+
+	/// Parses, type checks and translates `source` to a WebAssembly text
+	/// module printing its value.
+	pub fn compile_wat_str(&self, source: &str) -> Result<String, Error> {
+		let (expr, ty) = self.parse(source)?;
+		Ok(wat::compile(&optimize::fold(*expr), ty))
+	}
+
+	fn parse(&self, source: &str) -> Result<(Box<Expr>, Type), Error> {
+		let (expr, errors) = recover::parse(source);
+		if !errors.is_empty() {
+			return Err(Error::Parse(errors));
+		}
+		// The type checker and the compilers recurse as deeply as the
+		// program is nested, so the depth limit applies before them.
+		if let Some(max_depth) = self.limits.max_depth {
+			if expr.depth() > max_depth {
+				return Err(Error::Panic(MosesPanic::new(
+					PanicKind::DepthLimit,
+					format!("expressions nested deeper than {}", max_depth))));
+			}
+		}
+		let ty = types::type_of(&expr).map_err(Error::Type)?;
+		Ok((expr, ty))
+	}
+}
@@ -0,0 +1,688 @@
+extern crate lalrpop_util;
+#[cfg(test)]
+extern crate wat as wat_parser;
+
+#[cfg(test)]
+use std::time::Duration;
+
+// Private, since it accepts the placeholders `recover::parse` inserts for
+// errors. Only the tests use the start symbols besides `Expr`.
+#[allow(dead_code, unused_imports)]
+mod parser;
+pub mod ast;
+pub mod eval;
+pub mod types;
+pub mod interpreter;
+pub mod convert;
+pub mod bytecode;
+pub mod vm;
+pub mod c;
+pub mod wat;
+pub mod optimize;
+pub mod format;
+pub mod recover;
+
+pub use eval::{Value, MosesPanic, Limits};
+pub use interpreter::{Interpreter, Error};
+pub use convert::{IntoMoses, FromMoses};
+
+// TODO: Test for invalid input.
+
+#[test]
+fn parse_strings_and_chars() {
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("\"\"").unwrap()),
+		"»«");
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("\"This is a string.\"").unwrap()),
+		"»This is a string.«");
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("»חלךξκλолд«").unwrap()),
+		"»חלךξκλолд«");
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("'a'").unwrap()),
+		"›a‹");
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("›ξ‹").unwrap()),
+		"›ξ‹");
+}
+
+#[test]
+fn parse_mixed_expressions() {
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("1 + 4 == 2 * 3").unwrap()),
+		"Eq[Add[1, 4], Mul[2, 3]]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_BooleanExpr("1 ≤ 2 and True").unwrap()),
+		"And[Leq[1, 2], True]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_BooleanExpr("1 + 4 == 2 * 3 or not 4 * (1 + 3) ≠ 5").unwrap()),
+		"Or[Eq[Add[1, 4], Mul[2, 3]], Not[Neq[Mul[4, Add[1, 3]], 5]]]");
+	
+}
+
+#[test]
+fn parse_comparison() {
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("1 == 2").unwrap()),
+		"Eq[1, 2]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("1 != 2").unwrap()),
+		"Neq[1, 2]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("1 ≠ 2").unwrap()),
+		"Neq[1, 2]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("1 <= 2").unwrap()),
+		"Leq[1, 2]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("1 ≤ 2").unwrap()),
+		"Leq[1, 2]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("1 >= 2").unwrap()),
+		"Geq[1, 2]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("1 ≥ 2").unwrap()),
+		"Geq[1, 2]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("1 < 2").unwrap()),
+		"Lesser[1, 2]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_Comparison("1 > 2").unwrap()),
+		"Greater[1, 2]");
+}
+
+#[test]
+fn parse_logic_expression() {
+	assert_eq!(
+		&format!("{:?}", parser::parse_BooleanExpr("True").unwrap()),
+		"True");
+	assert_eq!(
+		&format!("{:?}", parser::parse_BooleanExpr("False").unwrap()),
+		"False");
+	assert_eq!(
+		&format!("{:?}", parser::parse_BooleanExpr("False and True").unwrap()),
+		"And[False, True]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_BooleanExpr("False or True").unwrap()),
+		"Or[False, True]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_BooleanExpr("False and True or True").unwrap()),
+		"Or[And[False, True], True]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_BooleanExpr("False and (True or False)").unwrap()),
+		"And[False, Or[True, False]]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_BooleanExpr("False and False and False").unwrap()),
+		"And[And[False, False], False]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_BooleanExpr("not False").unwrap()),
+		"Not[False]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_BooleanExpr("True and (False or not True)").unwrap()),
+		"And[True, Or[False, Not[True]]]");
+}
+
+#[test]
+fn parse_arithmetic_expr() {
+	assert_eq!(
+		&format!("{:?}", parser::parse_ArithmeticExpr("22").unwrap()),
+		"22");
+	assert_eq!(
+		&format!("{:?}", parser::parse_ArithmeticExpr("22 * 44").unwrap()),
+		"Mul[22, 44]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_ArithmeticExpr("44 + 66").unwrap()),
+		"Add[44, 66]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_ArithmeticExpr("22 * 44 + 66").unwrap()),
+		"Add[Mul[22, 44], 66]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_ArithmeticExpr("22 * (44 + 66)").unwrap()),
+		"Mul[22, Add[44, 66]]");
+	assert_eq!(
+		&format!("{:?}", parser::parse_ArithmeticExpr("22 * 44 * 66").unwrap()),
+		"Mul[Mul[22, 44], 66]");
+}
+
+#[test]
+fn evaluate_expressions() {
+	assert_eq!(
+		&format!("{}", eval::eval(&parser::parse_Expr("22 * (44 + 66)").unwrap()).unwrap()),
+		"2420");
+	assert_eq!(
+		&format!("{}", eval::eval(&parser::parse_Expr("7 / 2 - 5").unwrap()).unwrap()),
+		"-2");
+	assert_eq!(
+		&format!("{}", eval::eval(&parser::parse_Expr("1 + 4 == 5 and not 4 * (1 + 3) == 5").unwrap()).unwrap()),
+		"True");
+	assert_eq!(
+		&format!("{}", eval::eval(&parser::parse_Expr("1 ≤ 2 and False").unwrap()).unwrap()),
+		"False");
+}
+
+#[test]
+fn evaluate_panics() {
+	assert_eq!(
+		eval::eval(&parser::parse_Expr("1 + 4 / (2 - 2)").unwrap()).unwrap_err().kind,
+		eval::PanicKind::DivisionByZero);
+	assert_eq!(
+		&format!("{}", eval::eval(&parser::parse_Expr("4 / 0").unwrap()).unwrap_err()),
+		"panic: division by zero in Div[4, 0]");
+	assert_eq!(
+		eval::eval(&parser::parse_Expr("2147483647 + 1").unwrap()).unwrap_err().kind,
+		eval::PanicKind::Overflow);
+	// The second operand of `or` is not evaluated if the first one is true.
+	assert_eq!(
+		&format!("{}", eval::eval(&parser::parse_Expr("True or 1 / 0 == 0").unwrap()).unwrap()),
+		"True");
+}
+
+#[test]
+fn infer_types() {
+	assert_eq!(
+		&format!("{}", types::type_of(&parser::parse_Expr("22 * (44 + 66)").unwrap()).unwrap()),
+		"Number");
+	assert_eq!(
+		&format!("{}", types::type_of(&parser::parse_Expr("1 ≤ 2 and True").unwrap()).unwrap()),
+		"Bool");
+	assert_eq!(
+		&format!("{}", types::type_of(&ast::Expr::Op(ast::Opcode::Add, vec![
+			Box::new(ast::Expr::Number(1)), Box::new(ast::Expr::True)])).unwrap_err()),
+		"type error: Add expects Number, found Bool");
+}
+
+#[test]
+fn convert_values() {
+	assert_eq!(42u8.into_moses(), Value::Number(42));
+	assert_eq!("Schönfinkel".into_moses(), Value::Str("Schönfinkel".to_string()));
+	assert_eq!(i64::from_moses(Value::Number(-7)), Ok(-7));
+	assert_eq!(bool::from_moses(Value::Bool(true)), Ok(true));
+	assert_eq!(char::from_moses('ξ'.into_moses()), Ok('ξ'));
+	assert_eq!(String::from_moses(String::from("Moses").into_moses()), Ok(String::from("Moses")));
+	assert_eq!(
+		u8::from_moses(Value::Number(256)).unwrap_err().kind,
+		eval::PanicKind::Conversion);
+	assert_eq!(
+		&format!("{}", u32::from_moses(Value::Number(-1)).unwrap_err()),
+		"panic: cannot convert -1 to u32");
+	assert_eq!(
+		&format!("{}", i32::from_moses(Value::Bool(false)).unwrap_err()),
+		"panic: cannot convert False to i32");
+}
+
+#[test]
+fn evaluate_with_limits() {
+	let limited = |limits: eval::Limits, source: &str| {
+		eval::eval_limited(&parser::parse_Expr(source).unwrap(), &limits).map_err(|panic| panic.kind)
+	};
+	assert_eq!(
+		limited(eval::Limits { fuel: Some(5), ..Default::default() }, "1 + 2 * 3"),
+		Ok(Value::Number(7)));
+	assert_eq!(
+		limited(eval::Limits { fuel: Some(4), ..Default::default() }, "1 + 2 * 3"),
+		Err(eval::PanicKind::OutOfFuel));
+	assert_eq!(
+		limited(eval::Limits { max_depth: Some(3), ..Default::default() }, "1 + 2 * 3"),
+		Ok(Value::Number(7)));
+	assert_eq!(
+		limited(eval::Limits { max_depth: Some(2), ..Default::default() }, "1 + 2 * 3"),
+		Err(eval::PanicKind::DepthLimit));
+	assert_eq!(
+		limited(eval::Limits { timeout: Some(Duration::from_secs(0)), ..Default::default() }, "1 + 2"),
+		Err(eval::PanicKind::Timeout));
+	let greeting = ast::Expr::Str("Shalom".to_string());
+	assert_eq!(
+		eval::eval_limited(&greeting, &eval::Limits { max_heap: Some(5), ..Default::default() })
+			.unwrap_err().kind,
+		eval::PanicKind::HeapLimit);
+	// The depth limit applies before type checking, and programs of any
+	// size fail instead of overflowing the stack.
+	let interpreter = Interpreter::with_limits(eval::Limits {
+		max_depth: Some(100),
+		fuel: Some(1000),
+		..Default::default()
+	});
+	match interpreter.eval_str(&vec!["1"; 200].join(" + ")) {
+		Err(Error::Panic(panic)) => assert_eq!(panic.kind, eval::PanicKind::DepthLimit),
+		other => panic!("expected a panic, got {:?}", other),
+	}
+	assert!(interpreter.eval_str(&vec!["1"; 100_000].join(" + ")).is_err());
+	let deep = parser::parse_Expr(&vec!["1"; 100_000].join(" + ")).unwrap();
+	assert!(types::type_of(&deep).is_err());
+	let interpreter = Interpreter::with_limits(eval::Limits { fuel: Some(1), ..Default::default() });
+	assert_eq!(interpreter.eval_str("22").unwrap(), Value::Number(22));
+	match interpreter.eval_str("22 + 1") {
+		Err(Error::Panic(panic)) => assert_eq!(panic.kind, eval::PanicKind::OutOfFuel),
+		other => panic!("expected a panic, got {:?}", other),
+	}
+}
+
+#[test]
+fn compile_bytecode() {
+	use bytecode::Instruction::*;
+	assert_eq!(
+		bytecode::compile(&parser::parse_Expr("1 + 2 * 1").unwrap()).code,
+		vec![Constant(0), Constant(1), Constant(0), Op(ast::Opcode::Mul), Op(ast::Opcode::Add), Return]);
+	assert_eq!(
+		bytecode::compile(&parser::parse_Expr("False and True").unwrap()).code,
+		vec![Constant(0), JumpIfFalse(4), Pop, Constant(1), Return]);
+}
+
+#[test]
+fn run_bytecode() {
+	for source in &[
+		"22 * (44 + 66)",
+		"7 / 2 - 5",
+		"1 + 4 == 2 * 3 or not 4 * (1 + 3) ≠ 5",
+		"True and (False or not True)",
+		"False or 1 ≤ 2 and True",
+		"True or 1 / 0 == 0",
+		"1 + 4 / (2 - 2)",
+		"2147483647 + 1",
+	] {
+		let expr = parser::parse_Expr(source).unwrap();
+		assert_eq!(vm::run(&bytecode::compile(&expr)), eval::eval(&expr), "{}", source);
+	}
+}
+
+#[test]
+fn serialize_bytecode() {
+	let chunk = Interpreter::new().compile_str("1 ≤ 2 and not 3 / 0 == 1").unwrap();
+	let mut module = Vec::new();
+	chunk.write_to(&mut module).unwrap();
+	assert_eq!(&module[..4], bytecode::MAGIC);
+	assert_eq!(bytecode::Chunk::read_from(&mut &module[..]).unwrap(), chunk);
+	assert!(bytecode::Chunk::read_from(&mut &module[..module.len() - 1]).is_err());
+	module[4] = 99;
+	assert!(bytecode::Chunk::read_from(&mut &module[..]).is_err());
+
+	let mut chunk = bytecode::Chunk {
+		constants: vec![Value::Str("ξ".to_string()), Value::Char('ξ'), Value::Bool(true)],
+		code: vec![bytecode::Instruction::Constant(2), bytecode::Instruction::Return],
+	};
+	let mut module = Vec::new();
+	chunk.write_to(&mut module).unwrap();
+	assert_eq!(bytecode::Chunk::read_from(&mut &module[..]).unwrap(), chunk);
+	// A stack underflow is rejected when loading, not when running.
+	chunk.code = vec![bytecode::Instruction::Op(ast::Opcode::Add), bytecode::Instruction::Return];
+	let mut module = Vec::new();
+	chunk.write_to(&mut module).unwrap();
+	assert!(bytecode::Chunk::read_from(&mut &module[..]).is_err());
+	// So is an `and` the VM has no instruction for.
+	let chunk = bytecode::Chunk {
+		constants: vec![Value::Number(1)],
+		code: vec![
+			bytecode::Instruction::Constant(0),
+			bytecode::Instruction::Constant(0),
+			bytecode::Instruction::Op(ast::Opcode::And),
+			bytecode::Instruction::Return,
+		],
+	};
+	let mut module = Vec::new();
+	chunk.write_to(&mut module).unwrap();
+	assert!(bytecode::Chunk::read_from(&mut &module[..]).is_err());
+	// A string longer than the module fails without allocating its length.
+	let mut module = bytecode::MAGIC.to_vec();
+	module.extend_from_slice(&[1, 0, 1, 0, 0, 0, 2, 0xff, 0xff, 0xff, 0xff]);
+	assert_eq!(bytecode::Chunk::read_from(&mut &module[..]).unwrap_err().kind(), std::io::ErrorKind::InvalidData);
+}
+
+#[test]
+fn disassemble_bytecode() {
+	assert_eq!(
+		&format!("{}", bytecode::compile(&parser::parse_Expr("2 * 3 > 5 or False").unwrap())),
+		"moses bytecode version 1
+constants:
+     0  2
+     1  3
+     2  5
+     3  False
+code:
+     0  Constant 0  # 2
+     1  Constant 1  # 3
+     2  Op Mul
+     3  Constant 2  # 5
+     4  Op Greater
+     5  JumpIfTrue(8)
+     6  Pop
+     7  Constant 3  # False
+     8  Return
+");
+}
+
+#[test]
+fn compile_to_c() {
+	let program = c::compile(&parser::parse_Expr("1 + 2 * 3 == 7 or 4 / 0 > 1").unwrap(), types::Type::Bool);
+	assert!(program.ends_with("\
+int main(void) {
+	int32_t t0 = moses_mul(INT32_C(2), INT32_C(3));
+	int32_t t1 = moses_add(INT32_C(1), t0);
+	int t2 = t1 == INT32_C(7);
+	int t3 = t2;
+	if (!t3) {
+		int32_t t4 = moses_div(INT32_C(4), INT32_C(0));
+		int t5 = t4 > INT32_C(1);
+		t3 = t5;
+	}
+	moses_print_bool(t3);
+	return 0;
+}
+"), "{}", program);
+	assert!(c::compile(&ast::Expr::Str("»\"ξ\"«".to_string()), types::Type::Str)
+		.contains(r#"moses_print_str("\302\273\"\316\276\"\302\253");"#));
+}
+
+/// Builds and runs the C translation of `source` with the system C
+/// compiler, if there is one.
+#[cfg(test)]
+fn run_c(name: &str, source: &str) -> Option<std::process::Output> {
+	use std::fs::File;
+	use std::io::Write;
+	use std::process::Command;
+	let dir = std::env::temp_dir().join(format!("moses-c-test-{}-{}", std::process::id(), name));
+	std::fs::create_dir_all(&dir).unwrap();
+	let c_file = dir.join(format!("{}.c", name));
+	let binary = dir.join(name);
+	File::create(&c_file).unwrap()
+		.write_all(Interpreter::new().compile_c_str(source).unwrap().as_bytes()).unwrap();
+	let compiled = Command::new("cc").arg("-std=c99").arg("-o").arg(&binary).arg(&c_file).status().ok()?;
+	assert!(compiled.success(), "could not compile {}", source);
+	let output = Command::new(&binary).output().unwrap();
+	std::fs::remove_dir_all(&dir).unwrap();
+	Some(output)
+}
+
+#[test]
+fn run_compiled_c() {
+	for (i, source) in [
+		"22 * (44 + 66)",
+		"7 / 2 - 5",
+		"1 + 4 == 2 * 3 or not 4 * (1 + 3) ≠ 5",
+		"True and (False or not True)",
+		"True or 1 / 0 == 0",
+		"1 + 4 / (2 - 2)",
+		"2147483647 + 1",
+	].iter().enumerate() {
+		let output = match run_c(&i.to_string(), source) {
+			Some(output) => output,
+			None => return,
+		};
+		match Interpreter::new().eval_str(source) {
+			Ok(value) => {
+				assert!(output.status.success(), "{}", source);
+				assert_eq!(String::from_utf8(output.stdout).unwrap(), format!("{}\n", value));
+			}
+			Err(err) => {
+				assert_eq!(output.status.code(), Some(1), "{}", source);
+				assert_eq!(String::from_utf8(output.stderr).unwrap(), format!("{}\n", err));
+			}
+		}
+	}
+}
+
+/// Runs the WebAssembly translation of `source` with the WASI support of
+/// Node.js, if it is installed.
+#[cfg(test)]
+fn run_wasm(name: &str, source: &str) -> Option<std::process::Output> {
+	use std::process::Command;
+	const RUNNER: &str = "
+		const { WASI } = require('wasi');
+		const wasi = new WASI({ version: 'preview1', returnOnExit: false });
+		const module = new WebAssembly.Module(require('fs').readFileSync(process.argv[1]));
+		wasi.start(new WebAssembly.Instance(module, wasi.getImportObject()));
+	";
+	let module = wat_parser::parse_str(Interpreter::new().compile_wat_str(source).unwrap()).unwrap();
+	let file = std::env::temp_dir().join(format!("moses-wasm-test-{}-{}.wasm", std::process::id(), name));
+	std::fs::write(&file, module).unwrap();
+	let output = Command::new("node").arg("--no-warnings").arg("-e").arg(RUNNER).arg(&file).output();
+	std::fs::remove_file(&file).unwrap();
+	output.ok()
+}
+
+#[test]
+fn run_compiled_wasm() {
+	for (i, source) in [
+		"22 * (44 + 66)",
+		"7 / 2 - 5",
+		"0 - 2147483647 - 1",
+		"1 + 4 == 2 * 3 or not 4 * (1 + 3) ≠ 5",
+		"True and (False or not True)",
+		"True or 1 / 0 == 0",
+		"1 + 4 / (2 - 2)",
+		"2147483647 + 1",
+		"(0 - 2147483647 - 1) / (0 - 1)",
+		// Longer than the first page of memory.
+		&format!("\"{}\"", "x".repeat(70_000)),
+	].iter().enumerate() {
+		let output = match run_wasm(&i.to_string(), source) {
+			Some(output) => output,
+			None => return,
+		};
+		match Interpreter::new().eval_str(source) {
+			Ok(value) => {
+				assert!(output.status.success(), "{}: {:?}", source, output);
+				assert_eq!(String::from_utf8(output.stdout).unwrap(), format!("{}\n", value));
+			}
+			Err(err) => {
+				assert_eq!(output.status.code(), Some(1), "{}", source);
+				assert_eq!(String::from_utf8(output.stderr).unwrap(), format!("{}\n", err));
+			}
+		}
+	}
+}
+
+#[test]
+fn fold_constants() {
+	let fold = |source: &str| format!("{:?}", optimize::fold(*parser::parse_Expr(source).unwrap()));
+	assert_eq!(fold("4 * (1 + 3)"), "16");
+	assert_eq!(fold("1 + 4 == 2 * 3 or not 4 * (1 + 3) ≠ 5"), "False");
+	assert_eq!(fold("2147483647 + 1"), "Add[2147483647, 1]");
+	assert_eq!(fold("(1 + 1) / (2 - 2) * 1"), "Div[2, 0]");
+	assert_eq!(fold("0 + 7 / 0 - 0"), "Div[7, 0]");
+	assert_eq!(fold("not (not (1 / 0 == 1))"), "Eq[Div[1, 0], 1]");
+	assert_eq!(fold("1 / 0 == 1 and True"), "Eq[Div[1, 0], 1]");
+	assert_eq!(fold("1 ≤ 2 and 1 / 0 == 1"), "Eq[Div[1, 0], 1]");
+	assert_eq!(fold("1 > 2 and 1 / 0 == 1"), "False");
+	assert_eq!(fold("1 / 0 == 1 or True"), "Or[Eq[Div[1, 0], 1], True]");
+	assert_eq!(fold("1 / 0 == 1 and False"), "And[Eq[Div[1, 0], 1], False]");
+	for source in &[
+		"22 * (44 + 66)",
+		"True and (False or not True)",
+		"True or 1 / 0 == 0",
+		"1 + 4 / (2 - 2)",
+		"not (not (1 / 0 == 1))",
+		"1 / 0 == 1 or True",
+	] {
+		let expr = parser::parse_Expr(source).unwrap();
+		assert_eq!(eval::eval(&optimize::fold(*parser::parse_Expr(source).unwrap())), eval::eval(&expr), "{}", source);
+	}
+}
+
+#[test]
+fn format_expressions() {
+	use format::Spelling::*;
+	let format = |source: &str, spelling| format::format(&parser::parse_Expr(source).unwrap(), spelling);
+	assert_eq!(format("1+4==2*3", Ascii), "1 + 4 == 2 * 3");
+	assert_eq!(format("1 ≤ 2 and(True)", Ascii), "1 <= 2 and True");
+	assert_eq!(format("1 <= 2 and True", Unicode), "1 ≤ 2 and True");
+	assert_eq!(
+		format("((1 + 4) == 2 * 3) or not (4 * (1 + 3) != 5)", Ascii),
+		"1 + 4 == 2 * 3 or not 4 * (1 + 3) != 5");
+	assert_eq!(
+		format("1 + 4 == 2 * 3 or not 4 * (1 + 3) != 5", Unicode),
+		"1 + 4 == 2 · 3 or not 4 · (1 + 3) ≠ 5");
+	assert_eq!(format("22 ÷ (44 - (66 - 1)) - (2 - 1)", Ascii), "22 / (44 - (66 - 1)) - (2 - 1)");
+	assert_eq!(format("(22 / 44) * 66 >= (1)", Unicode), "22 ÷ 44 · 66 ≥ 1");
+	assert_eq!(format("False and (True or not (False and True))", Ascii), "False and (True or not (False and True))");
+	assert_eq!(format("(False and True) and (False or True)", Ascii), "False and True and (False or True)");
+	assert_eq!(format("not (not True)", Ascii), "not (not True)");
+}
+
+#[test]
+fn display_expressions() {
+	let display = |source: &str| format!("{}", parser::parse_Expr(source).unwrap());
+	assert_eq!(display("(1 + 4) == 2·3"), "1 + 4 == 2 * 3");
+	assert_eq!(display("1 - (2 - 3) - 4"), "1 - (2 - 3) - 4");
+	assert_eq!(display("»a \"quoted\" string«"), "»a \"quoted\" string«");
+	assert_eq!(display("\"a «quoted» string\""), "\"a «quoted» string\"");
+	assert_eq!(display("›'‹"), "›'‹");
+	assert_eq!(display("›ξ‹"), "'ξ'");
+}
+
+/// A small deterministic generator of random, well typed expressions.
+#[cfg(test)]
+struct RandomExprs(u64);
+
+#[cfg(test)]
+impl RandomExprs {
+	fn next(&mut self, bound: u64) -> u64 {
+		self.0 = self.0.wrapping_mul(6364136223846793005).wrapping_add(1442695040888963407);
+		(self.0 >> 33) % bound
+	}
+
+	fn number(&mut self, depth: u32) -> ast::Expr {
+		use ast::Opcode::*;
+		if depth == 0 || self.next(3) == 0 {
+			return ast::Expr::Number(self.next(1000) as i32);
+		}
+		let op = [Add, Sub, Mul, Div][self.next(4) as usize];
+		ast::Expr::Op(op, vec![Box::new(self.number(depth - 1)), Box::new(self.number(depth - 1))])
+	}
+
+	fn boolean(&mut self, depth: u32) -> ast::Expr {
+		use ast::Opcode::*;
+		if depth == 0 {
+			return if self.next(2) == 0 { ast::Expr::True } else { ast::Expr::False };
+		}
+		match self.next(5) {
+			0 => if self.next(2) == 0 { ast::Expr::True } else { ast::Expr::False },
+			1 => ast::Expr::Op(Not, vec![Box::new(self.boolean(depth - 1))]),
+			2 => {
+				let op = [Eq, Neq, Greater, Lesser, Geq, Leq][self.next(6) as usize];
+				ast::Expr::Op(op, vec![Box::new(self.number(depth - 1)), Box::new(self.number(depth - 1))])
+			}
+			_ => {
+				let op = [And, Or][self.next(2) as usize];
+				ast::Expr::Op(op, vec![Box::new(self.boolean(depth - 1)), Box::new(self.boolean(depth - 1))])
+			}
+		}
+	}
+}
+
+#[test]
+fn display_round_trips() {
+	let mut random = RandomExprs(0x5eed);
+	for _ in 0..1000 {
+		let expr = if random.next(2) == 0 { random.number(6) } else { random.boolean(6) };
+		let source = expr.to_string();
+		assert_eq!(*parser::parse_Expr(&source).unwrap(), expr, "{}", source);
+		let unicode = format::format(&expr, format::Spelling::Unicode);
+		assert_eq!(*parser::parse_Expr(&unicode).unwrap(), expr, "{}", unicode);
+	}
+}
+
+/// The syntax tree tree-sitter-moses builds for `expr`.
+#[cfg(test)]
+fn tree_sitter_tree(expr: &ast::Expr) -> String {
+	match *expr {
+		ast::Expr::Number(_) => "(number)".to_string(),
+		ast::Expr::True => "(true)".to_string(),
+		ast::Expr::False => "(false)".to_string(),
+		ast::Expr::Str(_) => "(string)".to_string(),
+		ast::Expr::Char(_) => "(character)".to_string(),
+		ast::Expr::Error => "(ERROR)".to_string(),
+		ast::Expr::Op(ast::Opcode::Not, ref operands) =>
+			format!("(unary_expression operand: {})", tree_sitter_tree(&operands[0])),
+		ast::Expr::Op(_, ref operands) => format!(
+			"(binary_expression left: {} right: {})",
+			tree_sitter_tree(&operands[0]), tree_sitter_tree(&operands[1])),
+	}
+}
+
+#[test]
+fn tree_sitter_corpus() {
+	for corpus in &[
+		include_str!("../tree-sitter-moses/test/corpus/literals.txt"),
+		include_str!("../tree-sitter-moses/test/corpus/expressions.txt"),
+		include_str!("../tree-sitter-moses/test/corpus/comments.txt"),
+	] {
+		// Every example is a header between lines of `=`, the source, a line
+		// of `-` and the expected tree.
+		let mut lines = corpus.lines().peekable();
+		while let Some(line) = lines.next() {
+			assert!(line.starts_with("==="), "expected a header, found {:?}", line);
+			let name = lines.next().unwrap();
+			lines.next();
+			let source: Vec<_> = lines.by_ref().take_while(|line| !line.starts_with("---")).collect();
+			let mut tree = Vec::new();
+			while lines.peek().is_some_and(|line| !line.starts_with("===")) {
+				tree.push(lines.next().unwrap());
+			}
+			let (expr, errors) = recover::parse(&source.join("\n"));
+			assert_eq!(errors, Vec::new(), "{}", name);
+			// The Rust parser skips comments.
+			let tree = tree.join(" ").replace("(comment)", "");
+			assert_eq!(
+				tree.split_whitespace().collect::<Vec<_>>().join(" ").replace(" )", ")"),
+				format!("(source_file {})", tree_sitter_tree(&expr)),
+				"{}", name);
+		}
+	}
+}
+
+#[test]
+fn recover_from_syntax_errors() {
+	let recover = |source: &str| {
+		let (expr, errors) = recover::parse(source);
+		let errors: Vec<_> = errors.iter().map(|err| err.to_string()).collect();
+		(format!("{:?}", expr), errors)
+	};
+	assert_eq!(recover("1 + 2 * 3"), ("Add[1, Mul[2, 3]]".to_string(), vec![]));
+	assert_eq!(
+		recover("1 + * 2"),
+		("Add[1, Mul[Error, 2]]".to_string(), vec!["1:5: unexpected `*`, expected `(` or a number".to_string()]));
+	assert_eq!(
+		recover("(1 + 2"),
+		("Add[1, 2]".to_string(), vec!["1:7: unexpected end of input, expected `!=`, `)`, `<`, `<=`, `==`, `>`, `>=`, `≠`, `≤` or `≥`".to_string()]));
+	assert_eq!(recover("True and").0, "And[True, Error]");
+	assert_eq!(recover("not").0, "Not[Error]");
+	assert_eq!(recover("1 2").0, "1");
+	assert_eq!(
+		recover("1 + 2 ) + 3"),
+		("Add[Add[1, 2], 3]".to_string(), vec!["1:7: unmatched `)`".to_string()]));
+	assert_eq!(
+		recover("1 + $"),
+		("Add[1, Error]".to_string(), vec!["1:5: unexpected character `$`".to_string()]));
+	assert_eq!(recover("1 + ￼").1, vec!["1:5: unexpected character `￼`".to_string()]);
+	assert_eq!(
+		recover("True and ￼"),
+		("And[True, Error]".to_string(), vec!["1:10: unexpected character `￼`".to_string()]));
+	assert_eq!(
+		recover("￼￼ + 1"),
+		("Add[Error, 1]".to_string(), vec![
+			"1:1: unexpected character `￼`".to_string(),
+			"1:2: unexpected character `￼`".to_string(),
+		]));
+	assert_eq!(recover("1 + # 2 (\n3 # »\n").0, "Add[1, 3]");
+	assert_eq!(recover("»#« # '\n").0, "»#«");
+	assert_eq!(recover("1 + # ￼").1, vec!["1:8: unexpected end of input, expected `(` or a number".to_string()]);
+	assert_eq!(
+		recover("1 + 99999999999"),
+		("Add[1, Error]".to_string(), vec!["1:5: number too large".to_string()]));
+	assert_eq!(
+		recover(&vec!["1"; 100_000].join(" + ")),
+		("Error".to_string(), vec![format!("1:1: expressions nested deeper than {}", ast::MAX_DEPTH)]));
+
+	// Three typos, three errors.
+	let (expr, errors) = recover("(1 + ) * 2 == 3 and\nnot 4 ≤ 5 5 or (True and)");
+	assert_eq!(expr, "Or[And[Eq[Mul[Add[1, Error], 2], 3], Not[Leq[4, 5]]], And[True, Error]]");
+	assert_eq!(errors, vec![
+		"1:6: unexpected `)`, expected `(` or a number".to_string(),
+		"2:11: unexpected `5`, expected `!=`, `)`, `*`, `+`, `-`, `/`, `<`, `<=`, `==`, `>`, `>=`, `and`, `or`, `·`, `÷`, `≠`, `≤` or `≥`".to_string(),
+		"2:25: unexpected `)`, expected `(`, `False`, `True`, `not`, a string, a character or a number".to_string(),
+	]);
+	assert_eq!(
+		Interpreter::new().eval_str("1 + * 2 - )").unwrap_err().to_string(),
+		"parse error: 1:5: unexpected `*`, expected `(` or a number\nparse error: 1:11: unmatched `)`");
+}
@@ -0,0 +1,63 @@
+use std::mem;
+use ast::{Expr, Opcode};
+use eval::{self, Value};
+
+/// Folds constant operations and simplifies algebraic identities like
+/// `not not x`, `x and True` or `x * 1`.
+///
+/// The result panics exactly when `expr` does: operations that panic, like
+/// `1 / 0`, are kept, and no operand that might panic is dropped.
+pub fn fold(mut expr: Expr) -> Expr {
+	// `Expr` implements `Drop`, so the operands are taken instead of moved.
+	let (op, mut operands) = match expr {
+		Expr::Op(op, ref mut operands) =>
+			(op, mem::take(operands).into_iter().map(|operand| Box::new(fold(*operand))).collect::<Vec<_>>()),
+		_ => return expr,
+	};
+	if operands.iter().all(|operand| is_literal(operand)) {
+		let folded = Expr::Op(op, operands);
+		return match eval::eval(&folded) {
+			Ok(value) => literal(value),
+			Err(_) => folded,
+		};
+	}
+	if op == Opcode::Not {
+		let mut operand = operands.pop().unwrap();
+		return match *operand {
+			Expr::Op(Opcode::Not, ref mut inner) => *inner.pop().unwrap(),
+			_ => Expr::Op(op, vec![operand]),
+		};
+	}
+	let snd = *operands.pop().unwrap();
+	let fst = *operands.pop().unwrap();
+	simplify(op, fst, snd)
+}
+
+fn simplify(op: Opcode, fst: Expr, snd: Expr) -> Expr {
+	use ast::Opcode::*;
+	match (op, &fst, &snd) {
+		// The first operand is always evaluated, so it can't be dropped, but
+		// a constant one decides whether the second operand is evaluated.
+		(And, &Expr::True, _) | (Or, &Expr::False, _) => snd,
+		(And, &Expr::False, _) | (Or, &Expr::True, _) => fst,
+		(And, _, &Expr::True) | (Or, _, &Expr::False) => fst,
+		(Add, &Expr::Number(0), _) | (Mul, &Expr::Number(1), _) => snd,
+		(Add, _, &Expr::Number(0)) | (Sub, _, &Expr::Number(0)) => fst,
+		(Mul, _, &Expr::Number(1)) | (Div, _, &Expr::Number(1)) => fst,
+		_ => Expr::Op(op, vec![Box::new(fst), Box::new(snd)]),
+	}
+}
+
+fn is_literal(expr: &Expr) -> bool {
+	!matches!(*expr, Expr::Op(..) | Expr::Error)
+}
+
+fn literal(value: Value) -> Expr {
+	match value {
+		Value::Number(n) => Expr::Number(n),
+		Value::Bool(true) => Expr::True,
+		Value::Bool(false) => Expr::False,
+		Value::Str(s) => Expr::Str(s),
+		Value::Char(c) => Expr::Char(c),
+	}
+}
@@ -1,5 +1,6 @@
 use std::str::FromStr;
 use ast::{Expr, Opcode};
+use lalrpop_util::ParseError;
 extern crate lalrpop_util as __lalrpop_util;
 
 mod __parse__ArithmeticExpr {
@@ -7,11 +8,11 @@ mod __parse__ArithmeticExpr {
 
     use std::str::FromStr;
     use ast::{Expr, Opcode};
+    use lalrpop_util::ParseError;
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(dead_code)]
     pub enum __Symbol<'input> {
         Term_22_21_3d_22(&'input str),
-        Term_22_5c_27_2e_5c_27_22(&'input str),
         Term_22_28_22(&'input str),
         Term_22_29_22(&'input str),
         Term_22_2a_22(&'input str),
@@ -25,16 +26,23 @@ mod __parse__ArithmeticExpr {
         Term_22_3e_3d_22(&'input str),
         Term_22False_22(&'input str),
         Term_22True_22(&'input str),
-        Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22(&'input str),
         Term_22and_22(&'input str),
         Term_22not_22(&'input str),
         Term_22or_22(&'input str),
-        Term_22_bb_2e_2a_ab_22(&'input str),
-        Term_22_203a_2e_2039_22(&'input str),
+        Term_22_b7_22(&'input str),
+        Term_22_f7_22(&'input str),
         Term_22_2260_22(&'input str),
         Term_22_2264_22(&'input str),
         Term_22_2265_22(&'input str),
+        Term_22_fffc_22(&'input str),
+        Term_22_fffc_fffc_22(&'input str),
+        Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23(&'input str),
+        Termr_23_22_27_5b_5e_27_5d_27_22_23(&'input str),
         Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+        Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23(&'input str),
+        Termr_23_22_203a_5b_5e_2039_5d_2039_22_23(&'input str),
+        Nt_40L(usize),
+        Nt_40R(usize),
         NtArithmeticExpr(Box<Expr>),
         NtBinExprTier_3cConOp_2c_20Negation_3e(Box<Expr>),
         NtBinExprTier_3cDisOp_2c_20Conjunction_3e(Box<Expr>),
@@ -48,6 +56,7 @@ mod __parse__ArithmeticExpr {
         NtConOp(Opcode),
         NtConjunction(Box<Expr>),
         NtDisOp(Opcode),
+        NtExpr(Box<Expr>),
         NtFactor(Box<Expr>),
         NtFactorOp(Opcode),
         NtNegOp(Opcode),
@@ -60,112 +69,127 @@ mod __parse__ArithmeticExpr {
         Nt____ArithmeticExpr(Box<Expr>),
         Nt____BooleanExpr(Box<Expr>),
         Nt____Comparison(Box<Expr>),
+        Nt____Expr(Box<Expr>),
     }
     const __ACTION: &'static [i32] = &[
         // State 0
-        0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9,
+        0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 10, 0, 0,
         // State 1
-        -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44,
+        -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54, -54,
         // State 2
-        0, 0, 0, -30, 11, -30, -30, 12, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -37, 12, -37, -37, 13, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, -1, 0, 14, 15, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, -3, 0, 17, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9,
+        -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11,
         // State 5
-        -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40,
+        -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49,
         // State 6
-        -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7,
+        -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9,
         // State 7
-        0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9,
+        0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 10, 0, 0,
         // State 8
-        -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35,
+        -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51,
         // State 9
-        0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9,
+        -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44,
         // State 10
-        -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31,
+        0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 10, 0, 0,
         // State 11
-        -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32,
+        -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38,
         // State 12
-        0, 0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9,
+        -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40,
         // State 13
-        -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38,
+        -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39,
         // State 14
-        -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39,
+        -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41,
         // State 15
-        0, 0, 0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 8, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 9, 0, 0, 0, 10, 0, 0,
         // State 16
-        -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6,
+        -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47,
         // State 17
-        -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,
+        -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48,
         // State 18
-        -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41,
+        0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 19
+        -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,
+        // State 20
+        -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10,
+        // State 21
+        -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50,
     ];
     const __EOF_ACTION: &'static [i32] = &[
         0,
-        -44,
-        -30,
-        -1,
+        -54,
+        -37,
+        -3,
+        -11,
+        -49,
         -9,
-        -40,
-        -7,
-        0,
-        -35,
         0,
-        -31,
-        -32,
+        -51,
+        -44,
         0,
         -38,
+        -40,
         -39,
+        -41,
+        0,
+        -47,
+        -48,
         0,
-        -6,
         -8,
-        -41,
+        -10,
+        -50,
     ];
     const __GOTO: &'static [i32] = &[
         // State 0
-        2, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 6, 0, 0, 7, 0, 0, 0, 0,
+        0, 0, 2, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 6, 0, 0, 7, 0, 0, 0, 0, 0,
         // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        16, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 6, 0, 0, 7, 0, 0, 0, 0,
+        0, 0, 19, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 5, 0, 0, 0, 6, 0, 0, 7, 0, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 17, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 20, 0, 0, 0, 0, 0,
         // State 11
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 12
-        0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 6, 0, 0, 7, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 13
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 15
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 6, 0, 0, 7, 0, 0, 0, 0, 0,
         // State 16
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 17
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 19
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 20
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 21
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
         const __TERMINAL: &'static [&'static str] = &[
             r###""!=""###,
-            r###""\'.\'""###,
             r###""(""###,
             r###"")""###,
             r###""*""###,
@@ -179,18 +203,23 @@ mod __parse__ArithmeticExpr {
             r###"">=""###,
             r###""False""###,
             r###""True""###,
-            r###""\\\".*\\\"""###,
             r###""and""###,
             r###""not""###,
             r###""or""###,
-            r###""».*«""###,
-            r###""›.‹""###,
+            r###""·""###,
+            r###""÷""###,
             r###""≠""###,
             r###""≤""###,
             r###""≥""###,
+            r###""￼""###,
+            r###""￼￼""###,
+            r###"r#"\"[^\"]*\""#"###,
+            r###"r#"'[^']'"#"###,
             r###"r#"[0-9]+"#"###,
+            r###"r#"»[^«]*«"#"###,
+            r###"r#"›[^‹]‹"#"###,
         ];
-        __ACTION[(__state * 25)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+        __ACTION[(__state * 29)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
             if state == 0 {
                 None
             } else {
@@ -202,7 +231,7 @@ mod __parse__ArithmeticExpr {
         'input,
     >(
         input: &'input str,
-    ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, (usize, &'input str), ()>>
+    ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, (usize, &'input str), (usize, usize, &'static str)>>
     {
         let mut __tokens = super::__intern_token::__Matcher::new(input);
         let mut __states = vec![0_i32];
@@ -218,31 +247,35 @@ mod __parse__ArithmeticExpr {
             };
             __last_location = __lookahead.2.clone();
             __integer = match __lookahead.1 {
-                (1, _) if true => 0,
-                (2, _) if true => 1,
-                (3, _) if true => 2,
-                (4, _) if true => 3,
-                (5, _) if true => 4,
-                (6, _) if true => 5,
-                (7, _) if true => 6,
-                (8, _) if true => 7,
-                (9, _) if true => 8,
-                (10, _) if true => 9,
-                (11, _) if true => 10,
-                (12, _) if true => 11,
-                (13, _) if true => 12,
-                (14, _) if true => 13,
-                (15, _) if true => 14,
-                (16, _) if true => 15,
-                (17, _) if true => 16,
-                (18, _) if true => 17,
-                (19, _) if true => 18,
-                (20, _) if true => 19,
-                (21, _) if true => 20,
-                (22, _) if true => 21,
-                (23, _) if true => 22,
-                (24, _) if true => 23,
+                (5, _) if true => 0,
+                (6, _) if true => 1,
+                (7, _) if true => 2,
+                (8, _) if true => 3,
+                (9, _) if true => 4,
+                (10, _) if true => 5,
+                (11, _) if true => 6,
+                (12, _) if true => 7,
+                (13, _) if true => 8,
+                (14, _) if true => 9,
+                (15, _) if true => 10,
+                (16, _) if true => 11,
+                (17, _) if true => 12,
+                (18, _) if true => 13,
+                (19, _) if true => 14,
+                (20, _) if true => 15,
+                (21, _) if true => 16,
+                (22, _) if true => 17,
+                (23, _) if true => 18,
+                (24, _) if true => 19,
+                (25, _) if true => 20,
+                (26, _) if true => 21,
+                (27, _) if true => 22,
+                (28, _) if true => 23,
                 (0, _) if true => 24,
+                (1, _) if true => 25,
+                (2, _) if true => 26,
+                (3, _) if true => 27,
+                (4, _) if true => 28,
                 _ => {
                     let __state = *__states.last().unwrap() as usize;
                     let __error = __lalrpop_util::ParseError::UnrecognizedToken {
@@ -254,107 +287,123 @@ mod __parse__ArithmeticExpr {
             };
             '__inner: loop {
                 let __state = *__states.last().unwrap() as usize;
-                let __action = __ACTION[__state * 25 + __integer];
+                let __action = __ACTION[__state * 29 + __integer];
                 if __action > 0 {
                     let __symbol = match __integer {
                         0 => match __lookahead.1 {
-                            (1, __tok0) => __Symbol::Term_22_21_3d_22((__tok0)),
+                            (5, __tok0) => __Symbol::Term_22_21_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         1 => match __lookahead.1 {
-                            (2, __tok0) => __Symbol::Term_22_5c_27_2e_5c_27_22((__tok0)),
+                            (6, __tok0) => __Symbol::Term_22_28_22((__tok0)),
                             _ => unreachable!(),
                         },
                         2 => match __lookahead.1 {
-                            (3, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                            (7, __tok0) => __Symbol::Term_22_29_22((__tok0)),
                             _ => unreachable!(),
                         },
                         3 => match __lookahead.1 {
-                            (4, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                            (8, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
                             _ => unreachable!(),
                         },
                         4 => match __lookahead.1 {
-                            (5, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
+                            (9, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
                             _ => unreachable!(),
                         },
                         5 => match __lookahead.1 {
-                            (6, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                            (10, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         6 => match __lookahead.1 {
-                            (7, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
+                            (11, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
                             _ => unreachable!(),
                         },
                         7 => match __lookahead.1 {
-                            (8, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
+                            (12, __tok0) => __Symbol::Term_22_3c_22((__tok0)),
                             _ => unreachable!(),
                         },
                         8 => match __lookahead.1 {
-                            (9, __tok0) => __Symbol::Term_22_3c_22((__tok0)),
+                            (13, __tok0) => __Symbol::Term_22_3c_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         9 => match __lookahead.1 {
-                            (10, __tok0) => __Symbol::Term_22_3c_3d_22((__tok0)),
+                            (14, __tok0) => __Symbol::Term_22_3d_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         10 => match __lookahead.1 {
-                            (11, __tok0) => __Symbol::Term_22_3d_3d_22((__tok0)),
+                            (15, __tok0) => __Symbol::Term_22_3e_22((__tok0)),
                             _ => unreachable!(),
                         },
                         11 => match __lookahead.1 {
-                            (12, __tok0) => __Symbol::Term_22_3e_22((__tok0)),
+                            (16, __tok0) => __Symbol::Term_22_3e_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         12 => match __lookahead.1 {
-                            (13, __tok0) => __Symbol::Term_22_3e_3d_22((__tok0)),
+                            (17, __tok0) => __Symbol::Term_22False_22((__tok0)),
                             _ => unreachable!(),
                         },
                         13 => match __lookahead.1 {
-                            (14, __tok0) => __Symbol::Term_22False_22((__tok0)),
+                            (18, __tok0) => __Symbol::Term_22True_22((__tok0)),
                             _ => unreachable!(),
                         },
                         14 => match __lookahead.1 {
-                            (15, __tok0) => __Symbol::Term_22True_22((__tok0)),
+                            (19, __tok0) => __Symbol::Term_22and_22((__tok0)),
                             _ => unreachable!(),
                         },
                         15 => match __lookahead.1 {
-                            (16, __tok0) => __Symbol::Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22((__tok0)),
+                            (20, __tok0) => __Symbol::Term_22not_22((__tok0)),
                             _ => unreachable!(),
                         },
                         16 => match __lookahead.1 {
-                            (17, __tok0) => __Symbol::Term_22and_22((__tok0)),
+                            (21, __tok0) => __Symbol::Term_22or_22((__tok0)),
                             _ => unreachable!(),
                         },
                         17 => match __lookahead.1 {
-                            (18, __tok0) => __Symbol::Term_22not_22((__tok0)),
+                            (22, __tok0) => __Symbol::Term_22_b7_22((__tok0)),
                             _ => unreachable!(),
                         },
                         18 => match __lookahead.1 {
-                            (19, __tok0) => __Symbol::Term_22or_22((__tok0)),
+                            (23, __tok0) => __Symbol::Term_22_f7_22((__tok0)),
                             _ => unreachable!(),
                         },
                         19 => match __lookahead.1 {
-                            (20, __tok0) => __Symbol::Term_22_bb_2e_2a_ab_22((__tok0)),
+                            (24, __tok0) => __Symbol::Term_22_2260_22((__tok0)),
                             _ => unreachable!(),
                         },
                         20 => match __lookahead.1 {
-                            (21, __tok0) => __Symbol::Term_22_203a_2e_2039_22((__tok0)),
+                            (25, __tok0) => __Symbol::Term_22_2264_22((__tok0)),
                             _ => unreachable!(),
                         },
                         21 => match __lookahead.1 {
-                            (22, __tok0) => __Symbol::Term_22_2260_22((__tok0)),
+                            (26, __tok0) => __Symbol::Term_22_2265_22((__tok0)),
                             _ => unreachable!(),
                         },
                         22 => match __lookahead.1 {
-                            (23, __tok0) => __Symbol::Term_22_2264_22((__tok0)),
+                            (27, __tok0) => __Symbol::Term_22_fffc_22((__tok0)),
                             _ => unreachable!(),
                         },
                         23 => match __lookahead.1 {
-                            (24, __tok0) => __Symbol::Term_22_2265_22((__tok0)),
+                            (28, __tok0) => __Symbol::Term_22_fffc_fffc_22((__tok0)),
                             _ => unreachable!(),
                         },
                         24 => match __lookahead.1 {
-                            (0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            (0, __tok0) => __Symbol::Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        25 => match __lookahead.1 {
+                            (1, __tok0) => __Symbol::Termr_23_22_27_5b_5e_27_5d_27_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        26 => match __lookahead.1 {
+                            (2, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        27 => match __lookahead.1 {
+                            (3, __tok0) => __Symbol::Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        28 => match __lookahead.1 {
+                            (4, __tok0) => __Symbol::Termr_23_22_203a_5b_5e_2039_5d_2039_22_23((__tok0)),
                             _ => unreachable!(),
                         },
                         _ => unreachable!(),
@@ -402,531 +451,653 @@ mod __parse__ArithmeticExpr {
         __states: &mut ::std::vec::Vec<i32>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
-    ) -> Option<Result<Box<Expr>,__lalrpop_util::ParseError<usize, (usize, &'input str), ()>>>
+    ) -> Option<Result<Box<Expr>,__lalrpop_util::ParseError<usize, (usize, &'input str), (usize, usize, &'static str)>>>
     {
         let __nonterminal = match -__action {
             1 => {
-                // ArithmeticExpr = BinExprTier<SummandOp, Factor> => ActionFn(23);
+                // @L =  => ActionFn(46);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action46::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40L(__nt), __end));
+                0
+            }
+            2 => {
+                // @R =  => ActionFn(45);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action45::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40R(__nt), __end));
+                1
+            }
+            3 => {
+                // ArithmeticExpr = BinExprTier<SummandOp, Factor> => ActionFn(29);
                 let __sym0 = __pop_NtBinExprTier_3cSummandOp_2c_20Factor_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action23::<>(input, __sym0);
+                let __nt = super::__action29::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtArithmeticExpr(__nt), __end));
-                0
+                2
             }
-            2 => {
-                // BinExprTier<ConOp, Negation> = BinExprTier<ConOp, Negation>, ConOp, Negation => ActionFn(42);
+            4 => {
+                // BinExprTier<ConOp, Negation> = BinExprTier<ConOp, Negation>, ConOp, Negation => ActionFn(53);
                 let __sym2 = __pop_NtNegation(__symbols);
                 let __sym1 = __pop_NtConOp(__symbols);
                 let __sym0 = __pop_NtBinExprTier_3cConOp_2c_20Negation_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action42::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action53::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cConOp_2c_20Negation_3e(__nt), __end));
-                1
+                3
             }
-            3 => {
-                // BinExprTier<ConOp, Negation> = Negation => ActionFn(43);
+            5 => {
+                // BinExprTier<ConOp, Negation> = Negation => ActionFn(54);
                 let __sym0 = __pop_NtNegation(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action43::<>(input, __sym0);
+                let __nt = super::__action54::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cConOp_2c_20Negation_3e(__nt), __end));
-                1
+                3
             }
-            4 => {
-                // BinExprTier<DisOp, Conjunction> = BinExprTier<DisOp, Conjunction>, DisOp, Conjunction => ActionFn(44);
+            6 => {
+                // BinExprTier<DisOp, Conjunction> = BinExprTier<DisOp, Conjunction>, DisOp, Conjunction => ActionFn(55);
                 let __sym2 = __pop_NtConjunction(__symbols);
                 let __sym1 = __pop_NtDisOp(__symbols);
                 let __sym0 = __pop_NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action44::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action55::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__nt), __end));
-                2
+                4
             }
-            5 => {
-                // BinExprTier<DisOp, Conjunction> = Conjunction => ActionFn(45);
+            7 => {
+                // BinExprTier<DisOp, Conjunction> = Conjunction => ActionFn(56);
                 let __sym0 = __pop_NtConjunction(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action45::<>(input, __sym0);
+                let __nt = super::__action56::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__nt), __end));
-                2
+                4
             }
-            6 => {
-                // BinExprTier<FactorOp, Term> = BinExprTier<FactorOp, Term>, FactorOp, Term => ActionFn(36);
+            8 => {
+                // BinExprTier<FactorOp, Term> = BinExprTier<FactorOp, Term>, FactorOp, Term => ActionFn(47);
                 let __sym2 = __pop_NtTerm(__symbols);
                 let __sym1 = __pop_NtFactorOp(__symbols);
                 let __sym0 = __pop_NtBinExprTier_3cFactorOp_2c_20Term_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action36::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action47::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cFactorOp_2c_20Term_3e(__nt), __end));
-                3
+                5
             }
-            7 => {
-                // BinExprTier<FactorOp, Term> = Term => ActionFn(37);
+            9 => {
+                // BinExprTier<FactorOp, Term> = Term => ActionFn(48);
                 let __sym0 = __pop_NtTerm(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action37::<>(input, __sym0);
+                let __nt = super::__action48::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cFactorOp_2c_20Term_3e(__nt), __end));
-                3
+                5
             }
-            8 => {
-                // BinExprTier<SummandOp, Factor> = BinExprTier<SummandOp, Factor>, SummandOp, Factor => ActionFn(38);
+            10 => {
+                // BinExprTier<SummandOp, Factor> = BinExprTier<SummandOp, Factor>, SummandOp, Factor => ActionFn(49);
                 let __sym2 = __pop_NtFactor(__symbols);
                 let __sym1 = __pop_NtSummandOp(__symbols);
                 let __sym0 = __pop_NtBinExprTier_3cSummandOp_2c_20Factor_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action38::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action49::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cSummandOp_2c_20Factor_3e(__nt), __end));
-                4
+                6
             }
-            9 => {
-                // BinExprTier<SummandOp, Factor> = Factor => ActionFn(39);
+            11 => {
+                // BinExprTier<SummandOp, Factor> = Factor => ActionFn(50);
                 let __sym0 = __pop_NtFactor(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action39::<>(input, __sym0);
+                let __nt = super::__action50::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cSummandOp_2c_20Factor_3e(__nt), __end));
-                4
+                6
             }
-            10 => {
-                // Boolean = "True" => ActionFn(6);
+            12 => {
+                // Boolean = "True" => ActionFn(9);
                 let __sym0 = __pop_Term_22True_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action6::<>(input, __sym0);
+                let __nt = super::__action9::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
-                5
+                7
             }
-            11 => {
-                // Boolean = "False" => ActionFn(7);
+            13 => {
+                // Boolean = "False" => ActionFn(10);
                 let __sym0 = __pop_Term_22False_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action7::<>(input, __sym0);
+                let __nt = super::__action10::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
-                5
+                7
             }
-            12 => {
-                // Boolean = "(", BooleanExpr, ")" => ActionFn(8);
+            14 => {
+                // Boolean = "(", BooleanExpr, ")" => ActionFn(11);
                 let __sym2 = __pop_Term_22_29_22(__symbols);
                 let __sym1 = __pop_NtBooleanExpr(__symbols);
                 let __sym0 = __pop_Term_22_28_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action8::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action11::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
-                5
+                7
             }
-            13 => {
-                // Boolean = Comparison => ActionFn(9);
+            15 => {
+                // Boolean = Comparison => ActionFn(12);
                 let __sym0 = __pop_NtComparison(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action9::<>(input, __sym0);
+                let __nt = super::__action12::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
-                5
+                7
             }
-            14 => {
-                // BooleanExpr = BinExprTier<DisOp, Conjunction> => ActionFn(3);
+            16 => {
+                // Boolean = "￼￼" => ActionFn(13);
+                let __sym0 = __pop_Term_22_fffc_fffc_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action13::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
+                7
+            }
+            17 => {
+                // BooleanExpr = BinExprTier<DisOp, Conjunction> => ActionFn(6);
                 let __sym0 = __pop_NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action3::<>(input, __sym0);
+                let __nt = super::__action6::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBooleanExpr(__nt), __end));
-                6
+                8
             }
-            15 => {
-                // Char = "\'.\'" => ActionFn(34);
-                let __sym0 = __pop_Term_22_5c_27_2e_5c_27_22(__symbols);
+            18 => {
+                // Char = r#"'[^']'"# => ActionFn(43);
+                let __sym0 = __pop_Termr_23_22_27_5b_5e_27_5d_27_22_23(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action34::<>(input, __sym0);
+                let __nt = super::__action43::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtChar(__nt), __end));
-                7
+                9
             }
-            16 => {
-                // Char = "›.‹" => ActionFn(35);
-                let __sym0 = __pop_Term_22_203a_2e_2039_22(__symbols);
+            19 => {
+                // Char = r#"›[^‹]‹"# => ActionFn(44);
+                let __sym0 = __pop_Termr_23_22_203a_5b_5e_2039_5d_2039_22_23(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action35::<>(input, __sym0);
+                let __nt = super::__action44::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtChar(__nt), __end));
-                7
+                9
             }
-            17 => {
-                // CompOp = "==" => ActionFn(14);
+            20 => {
+                // CompOp = "==" => ActionFn(20);
                 let __sym0 = __pop_Term_22_3d_3d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action14::<>(input, __sym0);
+                let __nt = super::__action20::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            18 => {
-                // CompOp = "!=" => ActionFn(15);
+            21 => {
+                // CompOp = "!=" => ActionFn(21);
                 let __sym0 = __pop_Term_22_21_3d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action15::<>(input, __sym0);
+                let __nt = super::__action21::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            19 => {
-                // CompOp = "≠" => ActionFn(16);
+            22 => {
+                // CompOp = "≠" => ActionFn(22);
                 let __sym0 = __pop_Term_22_2260_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action16::<>(input, __sym0);
+                let __nt = super::__action22::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            20 => {
-                // CompOp = "<=" => ActionFn(17);
+            23 => {
+                // CompOp = "<=" => ActionFn(23);
                 let __sym0 = __pop_Term_22_3c_3d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action17::<>(input, __sym0);
+                let __nt = super::__action23::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            21 => {
-                // CompOp = "≤" => ActionFn(18);
+            24 => {
+                // CompOp = "≤" => ActionFn(24);
                 let __sym0 = __pop_Term_22_2264_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action18::<>(input, __sym0);
+                let __nt = super::__action24::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            22 => {
-                // CompOp = ">=" => ActionFn(19);
+            25 => {
+                // CompOp = ">=" => ActionFn(25);
                 let __sym0 = __pop_Term_22_3e_3d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action19::<>(input, __sym0);
+                let __nt = super::__action25::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            23 => {
-                // CompOp = "≥" => ActionFn(20);
+            26 => {
+                // CompOp = "≥" => ActionFn(26);
                 let __sym0 = __pop_Term_22_2265_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action20::<>(input, __sym0);
+                let __nt = super::__action26::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            24 => {
-                // CompOp = "<" => ActionFn(21);
+            27 => {
+                // CompOp = "<" => ActionFn(27);
                 let __sym0 = __pop_Term_22_3c_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action21::<>(input, __sym0);
+                let __nt = super::__action27::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            25 => {
-                // CompOp = ">" => ActionFn(22);
+            28 => {
+                // CompOp = ">" => ActionFn(28);
                 let __sym0 = __pop_Term_22_3e_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action22::<>(input, __sym0);
+                let __nt = super::__action28::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            26 => {
-                // Comparison = ArithmeticExpr, CompOp, ArithmeticExpr => ActionFn(13);
+            29 => {
+                // Comparison = ArithmeticExpr, CompOp, ArithmeticExpr => ActionFn(17);
                 let __sym2 = __pop_NtArithmeticExpr(__symbols);
                 let __sym1 = __pop_NtCompOp(__symbols);
                 let __sym0 = __pop_NtArithmeticExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action13::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action17::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtComparison(__nt), __end));
-                9
+                11
             }
-            27 => {
-                // ConOp = "and" => ActionFn(11);
-                let __sym0 = __pop_Term_22and_22(__symbols);
+            30 => {
+                // Comparison = Str => ActionFn(18);
+                let __sym0 = __pop_NtStr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action11::<>(input, __sym0);
+                let __nt = super::__action18::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtConOp(__nt), __end));
-                10
+                __symbols.push((__start, __Symbol::NtComparison(__nt), __end));
+                11
             }
-            28 => {
-                // Conjunction = BinExprTier<ConOp, Negation> => ActionFn(4);
-                let __sym0 = __pop_NtBinExprTier_3cConOp_2c_20Negation_3e(__symbols);
+            31 => {
+                // Comparison = Char => ActionFn(19);
+                let __sym0 = __pop_NtChar(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action4::<>(input, __sym0);
+                let __nt = super::__action19::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtConjunction(__nt), __end));
+                __symbols.push((__start, __Symbol::NtComparison(__nt), __end));
                 11
             }
-            29 => {
-                // DisOp = "or" => ActionFn(10);
-                let __sym0 = __pop_Term_22or_22(__symbols);
+            32 => {
+                // ConOp = "and" => ActionFn(15);
+                let __sym0 = __pop_Term_22and_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action10::<>(input, __sym0);
+                let __nt = super::__action15::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtDisOp(__nt), __end));
+                __symbols.push((__start, __Symbol::NtConOp(__nt), __end));
                 12
             }
-            30 => {
-                // Factor = BinExprTier<FactorOp, Term> => ActionFn(24);
-                let __sym0 = __pop_NtBinExprTier_3cFactorOp_2c_20Term_3e(__symbols);
+            33 => {
+                // Conjunction = BinExprTier<ConOp, Negation> => ActionFn(7);
+                let __sym0 = __pop_NtBinExprTier_3cConOp_2c_20Negation_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action24::<>(input, __sym0);
+                let __nt = super::__action7::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                __symbols.push((__start, __Symbol::NtConjunction(__nt), __end));
                 13
             }
-            31 => {
-                // FactorOp = "*" => ActionFn(30);
-                let __sym0 = __pop_Term_22_2a_22(__symbols);
+            34 => {
+                // DisOp = "or" => ActionFn(14);
+                let __sym0 = __pop_Term_22or_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action30::<>(input, __sym0);
+                let __nt = super::__action14::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                __symbols.push((__start, __Symbol::NtDisOp(__nt), __end));
                 14
             }
-            32 => {
-                // FactorOp = "/" => ActionFn(31);
-                let __sym0 = __pop_Term_22_2f_22(__symbols);
+            35 => {
+                // Expr = BooleanExpr => ActionFn(4);
+                let __sym0 = __pop_NtBooleanExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action31::<>(input, __sym0);
+                let __nt = super::__action4::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
-                14
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                15
             }
-            33 => {
-                // NegOp = "not" => ActionFn(12);
-                let __sym0 = __pop_Term_22not_22(__symbols);
+            36 => {
+                // Expr = ArithmeticExpr => ActionFn(5);
+                let __sym0 = __pop_NtArithmeticExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action12::<>(input, __sym0);
+                let __nt = super::__action5::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtNegOp(__nt), __end));
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
                 15
             }
-            34 => {
-                // Negation = UnExprTier<NegOp, Boolean> => ActionFn(5);
-                let __sym0 = __pop_NtUnExprTier_3cNegOp_2c_20Boolean_3e(__symbols);
+            37 => {
+                // Factor = BinExprTier<FactorOp, Term> => ActionFn(30);
+                let __sym0 = __pop_NtBinExprTier_3cFactorOp_2c_20Term_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action5::<>(input, __sym0);
+                let __nt = super::__action30::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtNegation(__nt), __end));
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
                 16
             }
-            35 => {
-                // Num = r#"[0-9]+"# => ActionFn(27);
-                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+            38 => {
+                // FactorOp = "*" => ActionFn(37);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action27::<>(input, __sym0);
+                let __nt = super::__action37::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
                 17
             }
-            36 => {
-                // Str = "\\\".*\\\"" => ActionFn(32);
-                let __sym0 = __pop_Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22(__symbols);
+            39 => {
+                // FactorOp = "·" => ActionFn(38);
+                let __sym0 = __pop_Term_22_b7_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action32::<>(input, __sym0);
+                let __nt = super::__action38::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
-                18
-            }
-            37 => {
-                // Str = "».*«" => ActionFn(33);
-                let __sym0 = __pop_Term_22_bb_2e_2a_ab_22(__symbols);
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                17
+            }
+            40 => {
+                // FactorOp = "/" => ActionFn(39);
+                let __sym0 = __pop_Term_22_2f_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action33::<>(input, __sym0);
+                let __nt = super::__action39::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                17
+            }
+            41 => {
+                // FactorOp = "÷" => ActionFn(40);
+                let __sym0 = __pop_Term_22_f7_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action40::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                17
+            }
+            42 => {
+                // NegOp = "not" => ActionFn(16);
+                let __sym0 = __pop_Term_22not_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action16::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNegOp(__nt), __end));
                 18
             }
-            38 => {
-                // SummandOp = "+" => ActionFn(28);
+            43 => {
+                // Negation = UnExprTier<NegOp, Boolean> => ActionFn(8);
+                let __sym0 = __pop_NtUnExprTier_3cNegOp_2c_20Boolean_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action8::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNegation(__nt), __end));
+                19
+            }
+            44 => {
+                // Num = r#"[0-9]+"# => ActionFn(58);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = match super::__action58::<>(input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+                20
+            }
+            45 => {
+                // Str = r#"\"[^\"]*\""# => ActionFn(41);
+                let __sym0 = __pop_Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action41::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
+                21
+            }
+            46 => {
+                // Str = r#"»[^«]*«"# => ActionFn(42);
+                let __sym0 = __pop_Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action42::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
+                21
+            }
+            47 => {
+                // SummandOp = "+" => ActionFn(35);
                 let __sym0 = __pop_Term_22_2b_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action28::<>(input, __sym0);
+                let __nt = super::__action35::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtSummandOp(__nt), __end));
-                19
+                22
             }
-            39 => {
-                // SummandOp = "-" => ActionFn(29);
+            48 => {
+                // SummandOp = "-" => ActionFn(36);
                 let __sym0 = __pop_Term_22_2d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action29::<>(input, __sym0);
+                let __nt = super::__action36::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtSummandOp(__nt), __end));
-                19
+                22
             }
-            40 => {
-                // Term = Num => ActionFn(25);
+            49 => {
+                // Term = Num => ActionFn(31);
                 let __sym0 = __pop_NtNum(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action25::<>(input, __sym0);
+                let __nt = super::__action31::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
-                20
+                23
             }
-            41 => {
-                // Term = "(", ArithmeticExpr, ")" => ActionFn(26);
+            50 => {
+                // Term = "(", ArithmeticExpr, ")" => ActionFn(32);
                 let __sym2 = __pop_Term_22_29_22(__symbols);
                 let __sym1 = __pop_NtArithmeticExpr(__symbols);
                 let __sym0 = __pop_Term_22_28_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action26::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action32::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
-                20
+                23
             }
-            42 => {
-                // UnExprTier<NegOp, Boolean> = NegOp, Boolean => ActionFn(40);
+            51 => {
+                // Term = "￼" => ActionFn(33);
+                let __sym0 = __pop_Term_22_fffc_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action33::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                23
+            }
+            52 => {
+                // UnExprTier<NegOp, Boolean> = NegOp, Boolean => ActionFn(51);
                 let __sym1 = __pop_NtBoolean(__symbols);
                 let __sym0 = __pop_NtNegOp(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym1.2.clone();
-                let __nt = super::__action40::<>(input, __sym0, __sym1);
+                let __nt = super::__action51::<>(input, __sym0, __sym1);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 2);
                 __symbols.push((__start, __Symbol::NtUnExprTier_3cNegOp_2c_20Boolean_3e(__nt), __end));
-                21
+                24
             }
-            43 => {
-                // UnExprTier<NegOp, Boolean> = Boolean => ActionFn(41);
+            53 => {
+                // UnExprTier<NegOp, Boolean> = Boolean => ActionFn(52);
                 let __sym0 = __pop_NtBoolean(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action41::<>(input, __sym0);
+                let __nt = super::__action52::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtUnExprTier_3cNegOp_2c_20Boolean_3e(__nt), __end));
-                21
+                24
             }
-            44 => {
-                // __ArithmeticExpr = ArithmeticExpr => ActionFn(2);
+            54 => {
+                // __ArithmeticExpr = ArithmeticExpr => ActionFn(3);
                 let __sym0 = __pop_NtArithmeticExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action2::<>(input, __sym0);
+                let __nt = super::__action3::<>(input, __sym0);
                 return Some(Ok(__nt));
             }
-            45 => {
-                // __BooleanExpr = BooleanExpr => ActionFn(0);
+            55 => {
+                // __BooleanExpr = BooleanExpr => ActionFn(1);
                 let __sym0 = __pop_NtBooleanExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(input, __sym0);
+                let __nt = super::__action1::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::Nt____BooleanExpr(__nt), __end));
-                23
+                26
             }
-            46 => {
-                // __Comparison = Comparison => ActionFn(1);
+            56 => {
+                // __Comparison = Comparison => ActionFn(2);
                 let __sym0 = __pop_NtComparison(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action1::<>(input, __sym0);
+                let __nt = super::__action2::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::Nt____Comparison(__nt), __end));
-                24
+                27
+            }
+            57 => {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt____Expr(__nt), __end));
+                28
             }
             _ => panic!("invalid action code {}", __action)
         };
         let __state = *__states.last().unwrap() as usize;
-        let __next_state = __GOTO[__state * 25 + __nonterminal] - 1;
+        let __next_state = __GOTO[__state * 29 + __nonterminal] - 1;
         __states.push(__next_state);
         None
     }
@@ -940,16 +1111,6 @@ mod __parse__ArithmeticExpr {
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_5c_27_2e_5c_27_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_5c_27_2e_5c_27_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
     fn __pop_Term_22_28_22<
       'input,
     >(
@@ -1080,16 +1241,6 @@ mod __parse__ArithmeticExpr {
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
     fn __pop_Term_22and_22<
       'input,
     >(
@@ -1120,23 +1271,23 @@ mod __parse__ArithmeticExpr {
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_bb_2e_2a_ab_22<
+    fn __pop_Term_22_b7_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_bb_2e_2a_ab_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22_b7_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_203a_2e_2039_22<
+    fn __pop_Term_22_f7_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_203a_2e_2039_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22_f7_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
@@ -1170,6 +1321,46 @@ mod __parse__ArithmeticExpr {
             _ => panic!("symbol type mismatch")
         }
     }
+    fn __pop_Term_22_fffc_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_fffc_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_fffc_fffc_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_fffc_fffc_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_27_5b_5e_27_5d_27_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_27_5b_5e_27_5d_27_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
     fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
       'input,
     >(
@@ -1180,6 +1371,46 @@ mod __parse__ArithmeticExpr {
             _ => panic!("symbol type mismatch")
         }
     }
+    fn __pop_Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_203a_5b_5e_2039_5d_2039_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_203a_5b_5e_2039_5d_2039_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40L<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40L(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40R<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40R(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
     fn __pop_NtArithmeticExpr<
       'input,
     >(
@@ -1310,6 +1541,16 @@ mod __parse__ArithmeticExpr {
             _ => panic!("symbol type mismatch")
         }
     }
+    fn __pop_NtExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
     fn __pop_NtFactor<
       'input,
     >(
@@ -1430,6 +1671,16 @@ mod __parse__ArithmeticExpr {
             _ => panic!("symbol type mismatch")
         }
     }
+    fn __pop_Nt____Expr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
 }
 pub use self::__parse__ArithmeticExpr::parse_ArithmeticExpr;
 
@@ -1438,11 +1689,11 @@ mod __parse__BooleanExpr {
 
     use std::str::FromStr;
     use ast::{Expr, Opcode};
+    use lalrpop_util::ParseError;
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(dead_code)]
     pub enum __Symbol<'input> {
         Term_22_21_3d_22(&'input str),
-        Term_22_5c_27_2e_5c_27_22(&'input str),
         Term_22_28_22(&'input str),
         Term_22_29_22(&'input str),
         Term_22_2a_22(&'input str),
@@ -1456,16 +1707,23 @@ mod __parse__BooleanExpr {
         Term_22_3e_3d_22(&'input str),
         Term_22False_22(&'input str),
         Term_22True_22(&'input str),
-        Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22(&'input str),
         Term_22and_22(&'input str),
         Term_22not_22(&'input str),
         Term_22or_22(&'input str),
-        Term_22_bb_2e_2a_ab_22(&'input str),
-        Term_22_203a_2e_2039_22(&'input str),
+        Term_22_b7_22(&'input str),
+        Term_22_f7_22(&'input str),
         Term_22_2260_22(&'input str),
         Term_22_2264_22(&'input str),
         Term_22_2265_22(&'input str),
+        Term_22_fffc_22(&'input str),
+        Term_22_fffc_fffc_22(&'input str),
+        Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23(&'input str),
+        Termr_23_22_27_5b_5e_27_5d_27_22_23(&'input str),
         Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+        Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23(&'input str),
+        Termr_23_22_203a_5b_5e_2039_5d_2039_22_23(&'input str),
+        Nt_40L(usize),
+        Nt_40R(usize),
         NtArithmeticExpr(Box<Expr>),
         NtBinExprTier_3cConOp_2c_20Negation_3e(Box<Expr>),
         NtBinExprTier_3cDisOp_2c_20Conjunction_3e(Box<Expr>),
@@ -1479,6 +1737,7 @@ mod __parse__BooleanExpr {
         NtConOp(Opcode),
         NtConjunction(Box<Expr>),
         NtDisOp(Opcode),
+        NtExpr(Box<Expr>),
         NtFactor(Box<Expr>),
         NtFactorOp(Opcode),
         NtNegOp(Opcode),
@@ -1491,282 +1750,332 @@ mod __parse__BooleanExpr {
         Nt____ArithmeticExpr(Box<Expr>),
         Nt____BooleanExpr(Box<Expr>),
         Nt____Comparison(Box<Expr>),
+        Nt____Expr(Box<Expr>),
     }
     const __ACTION: &'static [i32] = &[
         // State 0
-        0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 19, 0, 0, 20, 0, 0, 0, 0, 0, 0, 21,
+        0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 21, 0, 22, 0, 0, 0, 0, 0, 0, 23, 24, 25, 26, 27, 28, 29,
         // State 1
-        23, 0, 0, 0, 0, 0, 0, 0, 24, 25, 26, 27, 28, 0, 0, 0, 0, 0, 0, 0, 0, 29, 30, 31, 0,
+        31, 0, 0, 0, 0, 0, 0, 32, 33, 34, 35, 36, 0, 0, 0, 0, 0, 0, 0, 37, 38, 39, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 0, -28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, -28, 0, 0, 0, 0, 0, 0,
+        0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, -14, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 35, 0, 0, 0, 0, 0, 0,
+        0, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        -30, 0, 0, -30, 37, -30, -30, 38, -30, -30, -30, -30, -30, 0, 0, 0, -30, 0, -30, 0, 0, -30, -30, -30, 0,
+        -37, 0, -37, 45, -37, -37, 46, -37, -37, -37, -37, -37, 0, 0, -37, 0, -37, 47, 48, -37, -37, -37, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        -1, 0, 0, -1, 0, 40, 41, 0, -1, -1, -1, -1, -1, 0, 0, 0, -1, 0, -1, 0, 0, -1, -1, -1, 0,
+        -3, 0, -3, 0, 50, 51, 0, -3, -3, -3, -3, -3, 0, 0, -3, 0, -3, 0, 0, -3, -3, -3, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43,
+        -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53,
         // State 7
-        -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45,
+        -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55, -55,
         // State 8
-        -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13,
+        -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31,
         // State 9
-        -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5,
+        -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15,
         // State 10
-        -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9,
+        -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7,
         // State 11
-        0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21,
+        -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11,
         // State 12
-        -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3, -3,
+        0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 21, 0, 0, 0, 0, 0, 0, 0, 0, 23, 24, 25, 26, 27, 28, 29,
         // State 13
-        -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40,
+        -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5,
         // State 14
-        -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7,
+        -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49,
         // State 15
-        -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34,
+        -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30,
         // State 16
-        0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 19, 0, 0, 20, 0, 0, 0, 0, 0, 0, 21,
+        -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9,
         // State 17
-        -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11,
+        -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43,
         // State 18
-        -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10,
+        0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 21, 0, 22, 0, 0, 0, 0, 0, 0, 23, 24, 25, 26, 27, 28, 29,
         // State 19
-        -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33, -33,
+        -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13,
         // State 20
-        -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35,
+        -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12,
         // State 21
-        0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21,
+        -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42,
         // State 22
-        -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18,
+        -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51,
         // State 23
-        -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24,
+        -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16,
         // State 24
-        -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20,
+        -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45,
         // State 25
-        -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17,
+        -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18,
         // State 26
-        -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25,
+        -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44,
         // State 27
-        -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22,
+        -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46,
         // State 28
-        -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19,
+        -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19,
         // State 29
-        -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21,
+        0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 27, 0, 0,
         // State 30
-        -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23,
+        -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21,
         // State 31
-        0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 19, 0, 0, 20, 0, 0, 0, 0, 0, 0, 21,
+        -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27,
         // State 32
-        -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27,
+        -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23,
         // State 33
-        0, 0, 17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 19, 0, 0, 20, 0, 0, 0, 0, 0, 0, 21,
+        -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20,
         // State 34
-        -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29,
+        -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28,
         // State 35
-        0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21,
+        -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25,
         // State 36
-        -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31,
+        -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22,
         // State 37
-        -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32,
+        -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24,
         // State 38
-        0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21,
+        -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26,
         // State 39
-        -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38,
+        0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 21, 0, 22, 0, 0, 0, 0, 0, 0, 23, 24, 25, 26, 27, 28, 29,
         // State 40
-        -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39,
+        -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32,
         // State 41
-        -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42,
+        0, 19, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 20, 21, 0, 22, 0, 0, 0, 0, 0, 0, 23, 24, 25, 26, 27, 28, 29,
         // State 42
-        23, 0, 0, 51, 0, 0, 0, 0, 24, 25, 26, 27, 28, 0, 0, 0, 0, 0, 0, 0, 0, 29, 30, 31, 0,
+        -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34,
         // State 43
-        0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 27, 0, 0,
         // State 44
-        -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26,
+        -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38,
         // State 45
-        0, 0, 46, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21,
+        -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40,
         // State 46
-        -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2, -2,
+        -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39,
         // State 47
-        -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4,
+        -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41,
         // State 48
-        -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6,
+        0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 27, 0, 0,
         // State 49
-        -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,
+        -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47,
         // State 50
-        -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41,
+        -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48,
         // State 51
-        -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12,
+        -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52,
         // State 52
-        0, 0, 0, 51, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        31, 0, 61, 0, 0, 0, 0, 32, 33, 34, 35, 36, 0, 0, 0, 0, 0, 0, 0, 37, 38, 39, 0, 0, 0, 0, 0, 0, 0,
+        // State 53
+        0, 0, 62, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 54
+        -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29,
+        // State 55
+        0, 56, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 23, 0, 0, 0, 27, 0, 0,
+        // State 56
+        -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4,
+        // State 57
+        -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6,
+        // State 58
+        -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,
+        // State 59
+        -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10,
+        // State 60
+        -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50,
+        // State 61
+        -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14,
+        // State 62
+        0, 0, 61, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     const __EOF_ACTION: &'static [i32] = &[
         0,
         0,
-        -28,
-        -14,
-        -30,
-        -1,
-        -43,
-        -45,
-        -13,
-        -5,
-        -9,
-        0,
+        -33,
+        -17,
+        -37,
         -3,
-        -40,
+        -53,
+        -55,
+        -31,
+        -15,
         -7,
-        -34,
-        0,
         -11,
-        -10,
-        -33,
-        -35,
         0,
+        -5,
+        -49,
+        -30,
+        -9,
+        -43,
+        0,
+        -13,
+        -12,
+        -42,
+        -51,
+        -16,
+        -45,
         -18,
-        -24,
-        -20,
-        -17,
-        -25,
-        -22,
+        -44,
+        -46,
         -19,
-        -21,
-        -23,
         0,
+        -21,
         -27,
+        -23,
+        -20,
+        -28,
+        -25,
+        -22,
+        -24,
+        -26,
         0,
-        -29,
-        0,
-        -31,
         -32,
         0,
+        -34,
+        0,
         -38,
+        -40,
         -39,
-        -42,
+        -41,
         0,
+        -47,
+        -48,
+        -52,
         0,
-        -26,
         0,
-        -2,
+        -29,
+        0,
         -4,
         -6,
         -8,
-        -41,
-        -12,
+        -10,
+        -50,
+        -14,
         0,
     ];
     const __GOTO: &'static [i32] = &[
         // State 0
-        2, 3, 4, 5, 6, 7, 8, 0, 0, 9, 0, 10, 0, 11, 0, 12, 13, 14, 0, 0, 15, 16, 0, 0, 0,
+        0, 0, 2, 3, 4, 5, 6, 7, 8, 9, 0, 10, 0, 11, 0, 0, 12, 0, 13, 14, 15, 16, 0, 17, 18, 0, 0, 0, 0,
         // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 34, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 49, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 11
-        2, 0, 0, 5, 6, 42, 0, 0, 0, 9, 0, 0, 0, 11, 0, 0, 0, 14, 0, 0, 15, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 2, 0, 0, 5, 6, 52, 0, 9, 0, 10, 0, 0, 0, 0, 12, 0, 0, 0, 15, 16, 0, 17, 0, 0, 0, 0, 0,
         // State 13
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 15
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 16
-        43, 3, 4, 5, 6, 7, 44, 0, 0, 9, 0, 10, 0, 11, 0, 12, 13, 14, 0, 0, 15, 16, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 17
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 53, 3, 4, 5, 6, 7, 54, 9, 0, 10, 0, 11, 0, 0, 12, 0, 13, 14, 15, 16, 0, 17, 18, 0, 0, 0, 0,
         // State 19
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 20
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 21
-        45, 0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 14, 0, 0, 15, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 22
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 23
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 24
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 25
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 26
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 27
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 28
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 29
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 55, 0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 15, 0, 0, 17, 0, 0, 0, 0, 0,
         // State 30
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 31
-        2, 0, 0, 5, 6, 7, 0, 0, 0, 9, 0, 0, 0, 11, 0, 12, 47, 14, 0, 0, 15, 16, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 32
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 33
-        2, 3, 0, 5, 6, 7, 0, 0, 0, 9, 0, 48, 0, 11, 0, 12, 13, 14, 0, 0, 15, 16, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 34
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 35
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 14, 0, 0, 49, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 36
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 37
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 38
-        0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 14, 0, 0, 15, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 39
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 2, 0, 0, 5, 6, 7, 0, 9, 0, 10, 0, 0, 0, 0, 12, 0, 13, 57, 15, 16, 0, 17, 18, 0, 0, 0, 0,
         // State 40
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 41
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 2, 3, 0, 5, 6, 7, 0, 9, 0, 10, 0, 58, 0, 0, 12, 0, 13, 14, 15, 16, 0, 17, 18, 0, 0, 0, 0,
         // State 42
-        0, 0, 0, 0, 0, 0, 0, 0, 22, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 43
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 15, 0, 0, 59, 0, 0, 0, 0, 0,
         // State 44
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 45
-        53, 0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 14, 0, 0, 15, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 46
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 47
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 48
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 60, 0, 0, 0, 15, 0, 0, 17, 0, 0, 0, 0, 0,
         // State 49
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 50
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 51
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 52
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 53
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 54
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 55
+        0, 0, 63, 0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 15, 0, 0, 17, 0, 0, 0, 0, 0,
+        // State 56
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 57
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 58
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 59
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 60
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 61
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 62
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
         const __TERMINAL: &'static [&'static str] = &[
             r###""!=""###,
-            r###""\'.\'""###,
             r###""(""###,
             r###"")""###,
             r###""*""###,
@@ -1780,18 +2089,23 @@ mod __parse__BooleanExpr {
             r###"">=""###,
             r###""False""###,
             r###""True""###,
-            r###""\\\".*\\\"""###,
             r###""and""###,
             r###""not""###,
             r###""or""###,
-            r###""».*«""###,
-            r###""›.‹""###,
+            r###""·""###,
+            r###""÷""###,
             r###""≠""###,
             r###""≤""###,
             r###""≥""###,
+            r###""￼""###,
+            r###""￼￼""###,
+            r###"r#"\"[^\"]*\""#"###,
+            r###"r#"'[^']'"#"###,
             r###"r#"[0-9]+"#"###,
+            r###"r#"»[^«]*«"#"###,
+            r###"r#"›[^‹]‹"#"###,
         ];
-        __ACTION[(__state * 25)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+        __ACTION[(__state * 29)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
             if state == 0 {
                 None
             } else {
@@ -1803,7 +2117,7 @@ mod __parse__BooleanExpr {
         'input,
     >(
         input: &'input str,
-    ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, (usize, &'input str), ()>>
+    ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, (usize, &'input str), (usize, usize, &'static str)>>
     {
         let mut __tokens = super::__intern_token::__Matcher::new(input);
         let mut __states = vec![0_i32];
@@ -1819,31 +2133,35 @@ mod __parse__BooleanExpr {
             };
             __last_location = __lookahead.2.clone();
             __integer = match __lookahead.1 {
-                (1, _) if true => 0,
-                (2, _) if true => 1,
-                (3, _) if true => 2,
-                (4, _) if true => 3,
-                (5, _) if true => 4,
-                (6, _) if true => 5,
-                (7, _) if true => 6,
-                (8, _) if true => 7,
-                (9, _) if true => 8,
-                (10, _) if true => 9,
-                (11, _) if true => 10,
-                (12, _) if true => 11,
-                (13, _) if true => 12,
-                (14, _) if true => 13,
-                (15, _) if true => 14,
-                (16, _) if true => 15,
-                (17, _) if true => 16,
-                (18, _) if true => 17,
-                (19, _) if true => 18,
-                (20, _) if true => 19,
-                (21, _) if true => 20,
-                (22, _) if true => 21,
-                (23, _) if true => 22,
-                (24, _) if true => 23,
+                (5, _) if true => 0,
+                (6, _) if true => 1,
+                (7, _) if true => 2,
+                (8, _) if true => 3,
+                (9, _) if true => 4,
+                (10, _) if true => 5,
+                (11, _) if true => 6,
+                (12, _) if true => 7,
+                (13, _) if true => 8,
+                (14, _) if true => 9,
+                (15, _) if true => 10,
+                (16, _) if true => 11,
+                (17, _) if true => 12,
+                (18, _) if true => 13,
+                (19, _) if true => 14,
+                (20, _) if true => 15,
+                (21, _) if true => 16,
+                (22, _) if true => 17,
+                (23, _) if true => 18,
+                (24, _) if true => 19,
+                (25, _) if true => 20,
+                (26, _) if true => 21,
+                (27, _) if true => 22,
+                (28, _) if true => 23,
                 (0, _) if true => 24,
+                (1, _) if true => 25,
+                (2, _) if true => 26,
+                (3, _) if true => 27,
+                (4, _) if true => 28,
                 _ => {
                     let __state = *__states.last().unwrap() as usize;
                     let __error = __lalrpop_util::ParseError::UnrecognizedToken {
@@ -1855,107 +2173,123 @@ mod __parse__BooleanExpr {
             };
             '__inner: loop {
                 let __state = *__states.last().unwrap() as usize;
-                let __action = __ACTION[__state * 25 + __integer];
+                let __action = __ACTION[__state * 29 + __integer];
                 if __action > 0 {
                     let __symbol = match __integer {
                         0 => match __lookahead.1 {
-                            (1, __tok0) => __Symbol::Term_22_21_3d_22((__tok0)),
+                            (5, __tok0) => __Symbol::Term_22_21_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         1 => match __lookahead.1 {
-                            (2, __tok0) => __Symbol::Term_22_5c_27_2e_5c_27_22((__tok0)),
+                            (6, __tok0) => __Symbol::Term_22_28_22((__tok0)),
                             _ => unreachable!(),
                         },
                         2 => match __lookahead.1 {
-                            (3, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                            (7, __tok0) => __Symbol::Term_22_29_22((__tok0)),
                             _ => unreachable!(),
                         },
                         3 => match __lookahead.1 {
-                            (4, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                            (8, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
                             _ => unreachable!(),
                         },
                         4 => match __lookahead.1 {
-                            (5, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
+                            (9, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
                             _ => unreachable!(),
                         },
                         5 => match __lookahead.1 {
-                            (6, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                            (10, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         6 => match __lookahead.1 {
-                            (7, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
+                            (11, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
                             _ => unreachable!(),
                         },
                         7 => match __lookahead.1 {
-                            (8, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
+                            (12, __tok0) => __Symbol::Term_22_3c_22((__tok0)),
                             _ => unreachable!(),
                         },
                         8 => match __lookahead.1 {
-                            (9, __tok0) => __Symbol::Term_22_3c_22((__tok0)),
+                            (13, __tok0) => __Symbol::Term_22_3c_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         9 => match __lookahead.1 {
-                            (10, __tok0) => __Symbol::Term_22_3c_3d_22((__tok0)),
+                            (14, __tok0) => __Symbol::Term_22_3d_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         10 => match __lookahead.1 {
-                            (11, __tok0) => __Symbol::Term_22_3d_3d_22((__tok0)),
+                            (15, __tok0) => __Symbol::Term_22_3e_22((__tok0)),
                             _ => unreachable!(),
                         },
                         11 => match __lookahead.1 {
-                            (12, __tok0) => __Symbol::Term_22_3e_22((__tok0)),
+                            (16, __tok0) => __Symbol::Term_22_3e_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         12 => match __lookahead.1 {
-                            (13, __tok0) => __Symbol::Term_22_3e_3d_22((__tok0)),
+                            (17, __tok0) => __Symbol::Term_22False_22((__tok0)),
                             _ => unreachable!(),
                         },
                         13 => match __lookahead.1 {
-                            (14, __tok0) => __Symbol::Term_22False_22((__tok0)),
+                            (18, __tok0) => __Symbol::Term_22True_22((__tok0)),
                             _ => unreachable!(),
                         },
                         14 => match __lookahead.1 {
-                            (15, __tok0) => __Symbol::Term_22True_22((__tok0)),
+                            (19, __tok0) => __Symbol::Term_22and_22((__tok0)),
                             _ => unreachable!(),
                         },
                         15 => match __lookahead.1 {
-                            (16, __tok0) => __Symbol::Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22((__tok0)),
+                            (20, __tok0) => __Symbol::Term_22not_22((__tok0)),
                             _ => unreachable!(),
                         },
                         16 => match __lookahead.1 {
-                            (17, __tok0) => __Symbol::Term_22and_22((__tok0)),
+                            (21, __tok0) => __Symbol::Term_22or_22((__tok0)),
                             _ => unreachable!(),
                         },
                         17 => match __lookahead.1 {
-                            (18, __tok0) => __Symbol::Term_22not_22((__tok0)),
+                            (22, __tok0) => __Symbol::Term_22_b7_22((__tok0)),
                             _ => unreachable!(),
                         },
                         18 => match __lookahead.1 {
-                            (19, __tok0) => __Symbol::Term_22or_22((__tok0)),
+                            (23, __tok0) => __Symbol::Term_22_f7_22((__tok0)),
                             _ => unreachable!(),
                         },
                         19 => match __lookahead.1 {
-                            (20, __tok0) => __Symbol::Term_22_bb_2e_2a_ab_22((__tok0)),
+                            (24, __tok0) => __Symbol::Term_22_2260_22((__tok0)),
                             _ => unreachable!(),
                         },
                         20 => match __lookahead.1 {
-                            (21, __tok0) => __Symbol::Term_22_203a_2e_2039_22((__tok0)),
+                            (25, __tok0) => __Symbol::Term_22_2264_22((__tok0)),
                             _ => unreachable!(),
                         },
                         21 => match __lookahead.1 {
-                            (22, __tok0) => __Symbol::Term_22_2260_22((__tok0)),
+                            (26, __tok0) => __Symbol::Term_22_2265_22((__tok0)),
                             _ => unreachable!(),
                         },
                         22 => match __lookahead.1 {
-                            (23, __tok0) => __Symbol::Term_22_2264_22((__tok0)),
+                            (27, __tok0) => __Symbol::Term_22_fffc_22((__tok0)),
                             _ => unreachable!(),
                         },
                         23 => match __lookahead.1 {
-                            (24, __tok0) => __Symbol::Term_22_2265_22((__tok0)),
+                            (28, __tok0) => __Symbol::Term_22_fffc_fffc_22((__tok0)),
                             _ => unreachable!(),
                         },
                         24 => match __lookahead.1 {
-                            (0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            (0, __tok0) => __Symbol::Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        25 => match __lookahead.1 {
+                            (1, __tok0) => __Symbol::Termr_23_22_27_5b_5e_27_5d_27_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        26 => match __lookahead.1 {
+                            (2, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        27 => match __lookahead.1 {
+                            (3, __tok0) => __Symbol::Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        28 => match __lookahead.1 {
+                            (4, __tok0) => __Symbol::Termr_23_22_203a_5b_5e_2039_5d_2039_22_23((__tok0)),
                             _ => unreachable!(),
                         },
                         _ => unreachable!(),
@@ -2003,531 +2337,653 @@ mod __parse__BooleanExpr {
         __states: &mut ::std::vec::Vec<i32>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
-    ) -> Option<Result<Box<Expr>,__lalrpop_util::ParseError<usize, (usize, &'input str), ()>>>
+    ) -> Option<Result<Box<Expr>,__lalrpop_util::ParseError<usize, (usize, &'input str), (usize, usize, &'static str)>>>
     {
         let __nonterminal = match -__action {
             1 => {
-                // ArithmeticExpr = BinExprTier<SummandOp, Factor> => ActionFn(23);
+                // @L =  => ActionFn(46);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action46::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40L(__nt), __end));
+                0
+            }
+            2 => {
+                // @R =  => ActionFn(45);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action45::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40R(__nt), __end));
+                1
+            }
+            3 => {
+                // ArithmeticExpr = BinExprTier<SummandOp, Factor> => ActionFn(29);
                 let __sym0 = __pop_NtBinExprTier_3cSummandOp_2c_20Factor_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action23::<>(input, __sym0);
+                let __nt = super::__action29::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtArithmeticExpr(__nt), __end));
-                0
+                2
             }
-            2 => {
-                // BinExprTier<ConOp, Negation> = BinExprTier<ConOp, Negation>, ConOp, Negation => ActionFn(42);
+            4 => {
+                // BinExprTier<ConOp, Negation> = BinExprTier<ConOp, Negation>, ConOp, Negation => ActionFn(53);
                 let __sym2 = __pop_NtNegation(__symbols);
                 let __sym1 = __pop_NtConOp(__symbols);
                 let __sym0 = __pop_NtBinExprTier_3cConOp_2c_20Negation_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action42::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action53::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cConOp_2c_20Negation_3e(__nt), __end));
-                1
+                3
             }
-            3 => {
-                // BinExprTier<ConOp, Negation> = Negation => ActionFn(43);
+            5 => {
+                // BinExprTier<ConOp, Negation> = Negation => ActionFn(54);
                 let __sym0 = __pop_NtNegation(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action43::<>(input, __sym0);
+                let __nt = super::__action54::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cConOp_2c_20Negation_3e(__nt), __end));
-                1
+                3
             }
-            4 => {
-                // BinExprTier<DisOp, Conjunction> = BinExprTier<DisOp, Conjunction>, DisOp, Conjunction => ActionFn(44);
+            6 => {
+                // BinExprTier<DisOp, Conjunction> = BinExprTier<DisOp, Conjunction>, DisOp, Conjunction => ActionFn(55);
                 let __sym2 = __pop_NtConjunction(__symbols);
                 let __sym1 = __pop_NtDisOp(__symbols);
                 let __sym0 = __pop_NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action44::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action55::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__nt), __end));
-                2
+                4
             }
-            5 => {
-                // BinExprTier<DisOp, Conjunction> = Conjunction => ActionFn(45);
+            7 => {
+                // BinExprTier<DisOp, Conjunction> = Conjunction => ActionFn(56);
                 let __sym0 = __pop_NtConjunction(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action45::<>(input, __sym0);
+                let __nt = super::__action56::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__nt), __end));
-                2
+                4
             }
-            6 => {
-                // BinExprTier<FactorOp, Term> = BinExprTier<FactorOp, Term>, FactorOp, Term => ActionFn(36);
+            8 => {
+                // BinExprTier<FactorOp, Term> = BinExprTier<FactorOp, Term>, FactorOp, Term => ActionFn(47);
                 let __sym2 = __pop_NtTerm(__symbols);
                 let __sym1 = __pop_NtFactorOp(__symbols);
                 let __sym0 = __pop_NtBinExprTier_3cFactorOp_2c_20Term_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action36::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action47::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cFactorOp_2c_20Term_3e(__nt), __end));
-                3
+                5
             }
-            7 => {
-                // BinExprTier<FactorOp, Term> = Term => ActionFn(37);
+            9 => {
+                // BinExprTier<FactorOp, Term> = Term => ActionFn(48);
                 let __sym0 = __pop_NtTerm(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action37::<>(input, __sym0);
+                let __nt = super::__action48::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cFactorOp_2c_20Term_3e(__nt), __end));
-                3
+                5
             }
-            8 => {
-                // BinExprTier<SummandOp, Factor> = BinExprTier<SummandOp, Factor>, SummandOp, Factor => ActionFn(38);
+            10 => {
+                // BinExprTier<SummandOp, Factor> = BinExprTier<SummandOp, Factor>, SummandOp, Factor => ActionFn(49);
                 let __sym2 = __pop_NtFactor(__symbols);
                 let __sym1 = __pop_NtSummandOp(__symbols);
                 let __sym0 = __pop_NtBinExprTier_3cSummandOp_2c_20Factor_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action38::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action49::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cSummandOp_2c_20Factor_3e(__nt), __end));
-                4
+                6
             }
-            9 => {
-                // BinExprTier<SummandOp, Factor> = Factor => ActionFn(39);
+            11 => {
+                // BinExprTier<SummandOp, Factor> = Factor => ActionFn(50);
                 let __sym0 = __pop_NtFactor(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action39::<>(input, __sym0);
+                let __nt = super::__action50::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cSummandOp_2c_20Factor_3e(__nt), __end));
-                4
+                6
             }
-            10 => {
-                // Boolean = "True" => ActionFn(6);
+            12 => {
+                // Boolean = "True" => ActionFn(9);
                 let __sym0 = __pop_Term_22True_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action6::<>(input, __sym0);
+                let __nt = super::__action9::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
-                5
+                7
             }
-            11 => {
-                // Boolean = "False" => ActionFn(7);
+            13 => {
+                // Boolean = "False" => ActionFn(10);
                 let __sym0 = __pop_Term_22False_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action7::<>(input, __sym0);
+                let __nt = super::__action10::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
-                5
+                7
             }
-            12 => {
-                // Boolean = "(", BooleanExpr, ")" => ActionFn(8);
+            14 => {
+                // Boolean = "(", BooleanExpr, ")" => ActionFn(11);
                 let __sym2 = __pop_Term_22_29_22(__symbols);
                 let __sym1 = __pop_NtBooleanExpr(__symbols);
                 let __sym0 = __pop_Term_22_28_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action8::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action11::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
-                5
+                7
             }
-            13 => {
-                // Boolean = Comparison => ActionFn(9);
+            15 => {
+                // Boolean = Comparison => ActionFn(12);
                 let __sym0 = __pop_NtComparison(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action9::<>(input, __sym0);
+                let __nt = super::__action12::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
-                5
+                7
             }
-            14 => {
-                // BooleanExpr = BinExprTier<DisOp, Conjunction> => ActionFn(3);
+            16 => {
+                // Boolean = "￼￼" => ActionFn(13);
+                let __sym0 = __pop_Term_22_fffc_fffc_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action13::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
+                7
+            }
+            17 => {
+                // BooleanExpr = BinExprTier<DisOp, Conjunction> => ActionFn(6);
                 let __sym0 = __pop_NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action3::<>(input, __sym0);
+                let __nt = super::__action6::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBooleanExpr(__nt), __end));
-                6
+                8
             }
-            15 => {
-                // Char = "\'.\'" => ActionFn(34);
-                let __sym0 = __pop_Term_22_5c_27_2e_5c_27_22(__symbols);
+            18 => {
+                // Char = r#"'[^']'"# => ActionFn(43);
+                let __sym0 = __pop_Termr_23_22_27_5b_5e_27_5d_27_22_23(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action34::<>(input, __sym0);
+                let __nt = super::__action43::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtChar(__nt), __end));
-                7
+                9
             }
-            16 => {
-                // Char = "›.‹" => ActionFn(35);
-                let __sym0 = __pop_Term_22_203a_2e_2039_22(__symbols);
+            19 => {
+                // Char = r#"›[^‹]‹"# => ActionFn(44);
+                let __sym0 = __pop_Termr_23_22_203a_5b_5e_2039_5d_2039_22_23(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action35::<>(input, __sym0);
+                let __nt = super::__action44::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtChar(__nt), __end));
-                7
+                9
             }
-            17 => {
-                // CompOp = "==" => ActionFn(14);
+            20 => {
+                // CompOp = "==" => ActionFn(20);
                 let __sym0 = __pop_Term_22_3d_3d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action14::<>(input, __sym0);
+                let __nt = super::__action20::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            18 => {
-                // CompOp = "!=" => ActionFn(15);
+            21 => {
+                // CompOp = "!=" => ActionFn(21);
                 let __sym0 = __pop_Term_22_21_3d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action15::<>(input, __sym0);
+                let __nt = super::__action21::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            19 => {
-                // CompOp = "≠" => ActionFn(16);
+            22 => {
+                // CompOp = "≠" => ActionFn(22);
                 let __sym0 = __pop_Term_22_2260_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action16::<>(input, __sym0);
+                let __nt = super::__action22::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            20 => {
-                // CompOp = "<=" => ActionFn(17);
+            23 => {
+                // CompOp = "<=" => ActionFn(23);
                 let __sym0 = __pop_Term_22_3c_3d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action17::<>(input, __sym0);
+                let __nt = super::__action23::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            21 => {
-                // CompOp = "≤" => ActionFn(18);
+            24 => {
+                // CompOp = "≤" => ActionFn(24);
                 let __sym0 = __pop_Term_22_2264_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action18::<>(input, __sym0);
+                let __nt = super::__action24::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            22 => {
-                // CompOp = ">=" => ActionFn(19);
+            25 => {
+                // CompOp = ">=" => ActionFn(25);
                 let __sym0 = __pop_Term_22_3e_3d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action19::<>(input, __sym0);
+                let __nt = super::__action25::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            23 => {
-                // CompOp = "≥" => ActionFn(20);
+            26 => {
+                // CompOp = "≥" => ActionFn(26);
                 let __sym0 = __pop_Term_22_2265_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action20::<>(input, __sym0);
+                let __nt = super::__action26::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            24 => {
-                // CompOp = "<" => ActionFn(21);
+            27 => {
+                // CompOp = "<" => ActionFn(27);
                 let __sym0 = __pop_Term_22_3c_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action21::<>(input, __sym0);
+                let __nt = super::__action27::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            25 => {
-                // CompOp = ">" => ActionFn(22);
+            28 => {
+                // CompOp = ">" => ActionFn(28);
                 let __sym0 = __pop_Term_22_3e_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action22::<>(input, __sym0);
+                let __nt = super::__action28::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            26 => {
-                // Comparison = ArithmeticExpr, CompOp, ArithmeticExpr => ActionFn(13);
+            29 => {
+                // Comparison = ArithmeticExpr, CompOp, ArithmeticExpr => ActionFn(17);
                 let __sym2 = __pop_NtArithmeticExpr(__symbols);
                 let __sym1 = __pop_NtCompOp(__symbols);
                 let __sym0 = __pop_NtArithmeticExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action13::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action17::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtComparison(__nt), __end));
-                9
+                11
             }
-            27 => {
-                // ConOp = "and" => ActionFn(11);
-                let __sym0 = __pop_Term_22and_22(__symbols);
+            30 => {
+                // Comparison = Str => ActionFn(18);
+                let __sym0 = __pop_NtStr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action11::<>(input, __sym0);
+                let __nt = super::__action18::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtConOp(__nt), __end));
-                10
+                __symbols.push((__start, __Symbol::NtComparison(__nt), __end));
+                11
             }
-            28 => {
-                // Conjunction = BinExprTier<ConOp, Negation> => ActionFn(4);
-                let __sym0 = __pop_NtBinExprTier_3cConOp_2c_20Negation_3e(__symbols);
+            31 => {
+                // Comparison = Char => ActionFn(19);
+                let __sym0 = __pop_NtChar(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action4::<>(input, __sym0);
+                let __nt = super::__action19::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtConjunction(__nt), __end));
+                __symbols.push((__start, __Symbol::NtComparison(__nt), __end));
                 11
             }
-            29 => {
-                // DisOp = "or" => ActionFn(10);
-                let __sym0 = __pop_Term_22or_22(__symbols);
+            32 => {
+                // ConOp = "and" => ActionFn(15);
+                let __sym0 = __pop_Term_22and_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action10::<>(input, __sym0);
+                let __nt = super::__action15::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtDisOp(__nt), __end));
+                __symbols.push((__start, __Symbol::NtConOp(__nt), __end));
                 12
             }
-            30 => {
-                // Factor = BinExprTier<FactorOp, Term> => ActionFn(24);
-                let __sym0 = __pop_NtBinExprTier_3cFactorOp_2c_20Term_3e(__symbols);
+            33 => {
+                // Conjunction = BinExprTier<ConOp, Negation> => ActionFn(7);
+                let __sym0 = __pop_NtBinExprTier_3cConOp_2c_20Negation_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action24::<>(input, __sym0);
+                let __nt = super::__action7::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                __symbols.push((__start, __Symbol::NtConjunction(__nt), __end));
                 13
             }
-            31 => {
-                // FactorOp = "*" => ActionFn(30);
-                let __sym0 = __pop_Term_22_2a_22(__symbols);
+            34 => {
+                // DisOp = "or" => ActionFn(14);
+                let __sym0 = __pop_Term_22or_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action30::<>(input, __sym0);
+                let __nt = super::__action14::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                __symbols.push((__start, __Symbol::NtDisOp(__nt), __end));
                 14
             }
-            32 => {
-                // FactorOp = "/" => ActionFn(31);
-                let __sym0 = __pop_Term_22_2f_22(__symbols);
+            35 => {
+                // Expr = BooleanExpr => ActionFn(4);
+                let __sym0 = __pop_NtBooleanExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action31::<>(input, __sym0);
+                let __nt = super::__action4::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
-                14
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                15
             }
-            33 => {
-                // NegOp = "not" => ActionFn(12);
-                let __sym0 = __pop_Term_22not_22(__symbols);
+            36 => {
+                // Expr = ArithmeticExpr => ActionFn(5);
+                let __sym0 = __pop_NtArithmeticExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action12::<>(input, __sym0);
+                let __nt = super::__action5::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtNegOp(__nt), __end));
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
                 15
             }
-            34 => {
-                // Negation = UnExprTier<NegOp, Boolean> => ActionFn(5);
-                let __sym0 = __pop_NtUnExprTier_3cNegOp_2c_20Boolean_3e(__symbols);
+            37 => {
+                // Factor = BinExprTier<FactorOp, Term> => ActionFn(30);
+                let __sym0 = __pop_NtBinExprTier_3cFactorOp_2c_20Term_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action5::<>(input, __sym0);
+                let __nt = super::__action30::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtNegation(__nt), __end));
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
                 16
             }
-            35 => {
-                // Num = r#"[0-9]+"# => ActionFn(27);
-                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+            38 => {
+                // FactorOp = "*" => ActionFn(37);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action27::<>(input, __sym0);
+                let __nt = super::__action37::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
                 17
             }
-            36 => {
-                // Str = "\\\".*\\\"" => ActionFn(32);
-                let __sym0 = __pop_Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22(__symbols);
+            39 => {
+                // FactorOp = "·" => ActionFn(38);
+                let __sym0 = __pop_Term_22_b7_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action32::<>(input, __sym0);
+                let __nt = super::__action38::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
-                18
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                17
             }
-            37 => {
-                // Str = "».*«" => ActionFn(33);
-                let __sym0 = __pop_Term_22_bb_2e_2a_ab_22(__symbols);
+            40 => {
+                // FactorOp = "/" => ActionFn(39);
+                let __sym0 = __pop_Term_22_2f_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action33::<>(input, __sym0);
+                let __nt = super::__action39::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
-                18
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                17
             }
-            38 => {
-                // SummandOp = "+" => ActionFn(28);
-                let __sym0 = __pop_Term_22_2b_22(__symbols);
+            41 => {
+                // FactorOp = "÷" => ActionFn(40);
+                let __sym0 = __pop_Term_22_f7_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action28::<>(input, __sym0);
+                let __nt = super::__action40::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtSummandOp(__nt), __end));
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                17
+            }
+            42 => {
+                // NegOp = "not" => ActionFn(16);
+                let __sym0 = __pop_Term_22not_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action16::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNegOp(__nt), __end));
+                18
+            }
+            43 => {
+                // Negation = UnExprTier<NegOp, Boolean> => ActionFn(8);
+                let __sym0 = __pop_NtUnExprTier_3cNegOp_2c_20Boolean_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action8::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNegation(__nt), __end));
                 19
             }
-            39 => {
-                // SummandOp = "-" => ActionFn(29);
+            44 => {
+                // Num = r#"[0-9]+"# => ActionFn(58);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = match super::__action58::<>(input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+                20
+            }
+            45 => {
+                // Str = r#"\"[^\"]*\""# => ActionFn(41);
+                let __sym0 = __pop_Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action41::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
+                21
+            }
+            46 => {
+                // Str = r#"»[^«]*«"# => ActionFn(42);
+                let __sym0 = __pop_Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action42::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
+                21
+            }
+            47 => {
+                // SummandOp = "+" => ActionFn(35);
+                let __sym0 = __pop_Term_22_2b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action35::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSummandOp(__nt), __end));
+                22
+            }
+            48 => {
+                // SummandOp = "-" => ActionFn(36);
                 let __sym0 = __pop_Term_22_2d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action29::<>(input, __sym0);
+                let __nt = super::__action36::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtSummandOp(__nt), __end));
-                19
+                22
             }
-            40 => {
-                // Term = Num => ActionFn(25);
+            49 => {
+                // Term = Num => ActionFn(31);
                 let __sym0 = __pop_NtNum(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action25::<>(input, __sym0);
+                let __nt = super::__action31::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
-                20
+                23
             }
-            41 => {
-                // Term = "(", ArithmeticExpr, ")" => ActionFn(26);
+            50 => {
+                // Term = "(", ArithmeticExpr, ")" => ActionFn(32);
                 let __sym2 = __pop_Term_22_29_22(__symbols);
                 let __sym1 = __pop_NtArithmeticExpr(__symbols);
                 let __sym0 = __pop_Term_22_28_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action26::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action32::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
-                20
+                23
             }
-            42 => {
-                // UnExprTier<NegOp, Boolean> = NegOp, Boolean => ActionFn(40);
+            51 => {
+                // Term = "￼" => ActionFn(33);
+                let __sym0 = __pop_Term_22_fffc_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action33::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                23
+            }
+            52 => {
+                // UnExprTier<NegOp, Boolean> = NegOp, Boolean => ActionFn(51);
                 let __sym1 = __pop_NtBoolean(__symbols);
                 let __sym0 = __pop_NtNegOp(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym1.2.clone();
-                let __nt = super::__action40::<>(input, __sym0, __sym1);
+                let __nt = super::__action51::<>(input, __sym0, __sym1);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 2);
                 __symbols.push((__start, __Symbol::NtUnExprTier_3cNegOp_2c_20Boolean_3e(__nt), __end));
-                21
+                24
             }
-            43 => {
-                // UnExprTier<NegOp, Boolean> = Boolean => ActionFn(41);
+            53 => {
+                // UnExprTier<NegOp, Boolean> = Boolean => ActionFn(52);
                 let __sym0 = __pop_NtBoolean(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action41::<>(input, __sym0);
+                let __nt = super::__action52::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtUnExprTier_3cNegOp_2c_20Boolean_3e(__nt), __end));
-                21
+                24
             }
-            44 => {
-                // __ArithmeticExpr = ArithmeticExpr => ActionFn(2);
+            54 => {
+                // __ArithmeticExpr = ArithmeticExpr => ActionFn(3);
                 let __sym0 = __pop_NtArithmeticExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action2::<>(input, __sym0);
+                let __nt = super::__action3::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::Nt____ArithmeticExpr(__nt), __end));
-                22
+                25
             }
-            45 => {
-                // __BooleanExpr = BooleanExpr => ActionFn(0);
+            55 => {
+                // __BooleanExpr = BooleanExpr => ActionFn(1);
                 let __sym0 = __pop_NtBooleanExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(input, __sym0);
+                let __nt = super::__action1::<>(input, __sym0);
                 return Some(Ok(__nt));
             }
-            46 => {
-                // __Comparison = Comparison => ActionFn(1);
+            56 => {
+                // __Comparison = Comparison => ActionFn(2);
                 let __sym0 = __pop_NtComparison(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action1::<>(input, __sym0);
+                let __nt = super::__action2::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::Nt____Comparison(__nt), __end));
-                24
+                27
+            }
+            57 => {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt____Expr(__nt), __end));
+                28
             }
             _ => panic!("invalid action code {}", __action)
         };
         let __state = *__states.last().unwrap() as usize;
-        let __next_state = __GOTO[__state * 25 + __nonterminal] - 1;
+        let __next_state = __GOTO[__state * 29 + __nonterminal] - 1;
         __states.push(__next_state);
         None
     }
@@ -2541,16 +2997,6 @@ mod __parse__BooleanExpr {
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_5c_27_2e_5c_27_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_5c_27_2e_5c_27_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
     fn __pop_Term_22_28_22<
       'input,
     >(
@@ -2681,16 +3127,6 @@ mod __parse__BooleanExpr {
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
     fn __pop_Term_22and_22<
       'input,
     >(
@@ -2721,23 +3157,23 @@ mod __parse__BooleanExpr {
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_bb_2e_2a_ab_22<
+    fn __pop_Term_22_b7_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_bb_2e_2a_ab_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22_b7_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_203a_2e_2039_22<
+    fn __pop_Term_22_f7_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_203a_2e_2039_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22_f7_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
@@ -2771,6 +3207,46 @@ mod __parse__BooleanExpr {
             _ => panic!("symbol type mismatch")
         }
     }
+    fn __pop_Term_22_fffc_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_fffc_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_fffc_fffc_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_fffc_fffc_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_27_5b_5e_27_5d_27_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_27_5b_5e_27_5d_27_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
     fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
       'input,
     >(
@@ -2781,6 +3257,46 @@ mod __parse__BooleanExpr {
             _ => panic!("symbol type mismatch")
         }
     }
+    fn __pop_Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_203a_5b_5e_2039_5d_2039_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_203a_5b_5e_2039_5d_2039_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40L<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40L(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40R<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40R(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
     fn __pop_NtArithmeticExpr<
       'input,
     >(
@@ -2911,6 +3427,16 @@ mod __parse__BooleanExpr {
             _ => panic!("symbol type mismatch")
         }
     }
+    fn __pop_NtExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
     fn __pop_NtFactor<
       'input,
     >(
@@ -3031,6 +3557,16 @@ mod __parse__BooleanExpr {
             _ => panic!("symbol type mismatch")
         }
     }
+    fn __pop_Nt____Expr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
 }
 pub use self::__parse__BooleanExpr::parse_BooleanExpr;
 
@@ -3039,11 +3575,11 @@ mod __parse__Comparison {
 
     use std::str::FromStr;
     use ast::{Expr, Opcode};
+    use lalrpop_util::ParseError;
     extern crate lalrpop_util as __lalrpop_util;
     #[allow(dead_code)]
     pub enum __Symbol<'input> {
         Term_22_21_3d_22(&'input str),
-        Term_22_5c_27_2e_5c_27_22(&'input str),
         Term_22_28_22(&'input str),
         Term_22_29_22(&'input str),
         Term_22_2a_22(&'input str),
@@ -3057,16 +3593,23 @@ mod __parse__Comparison {
         Term_22_3e_3d_22(&'input str),
         Term_22False_22(&'input str),
         Term_22True_22(&'input str),
-        Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22(&'input str),
         Term_22and_22(&'input str),
         Term_22not_22(&'input str),
         Term_22or_22(&'input str),
-        Term_22_bb_2e_2a_ab_22(&'input str),
-        Term_22_203a_2e_2039_22(&'input str),
+        Term_22_b7_22(&'input str),
+        Term_22_f7_22(&'input str),
         Term_22_2260_22(&'input str),
         Term_22_2264_22(&'input str),
         Term_22_2265_22(&'input str),
+        Term_22_fffc_22(&'input str),
+        Term_22_fffc_fffc_22(&'input str),
+        Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23(&'input str),
+        Termr_23_22_27_5b_5e_27_5d_27_22_23(&'input str),
         Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+        Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23(&'input str),
+        Termr_23_22_203a_5b_5e_2039_5d_2039_22_23(&'input str),
+        Nt_40L(usize),
+        Nt_40R(usize),
         NtArithmeticExpr(Box<Expr>),
         NtBinExprTier_3cConOp_2c_20Negation_3e(Box<Expr>),
         NtBinExprTier_3cDisOp_2c_20Conjunction_3e(Box<Expr>),
@@ -3080,6 +3623,7 @@ mod __parse__Comparison {
         NtConOp(Opcode),
         NtConjunction(Box<Expr>),
         NtDisOp(Opcode),
+        NtExpr(Box<Expr>),
         NtFactor(Box<Expr>),
         NtFactorOp(Opcode),
         NtNegOp(Opcode),
@@ -3092,172 +3636,217 @@ mod __parse__Comparison {
         Nt____ArithmeticExpr(Box<Expr>),
         Nt____BooleanExpr(Box<Expr>),
         Nt____Comparison(Box<Expr>),
+        Nt____Expr(Box<Expr>),
     }
     const __ACTION: &'static [i32] = &[
         // State 0
-        0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10,
+        0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 13, 14, 15, 16, 17,
         // State 1
-        12, 0, 0, 0, 0, 0, 0, 0, 13, 14, 15, 16, 17, 0, 0, 0, 0, 0, 0, 0, 0, 18, 19, 20, 0,
+        19, 0, 0, 0, 0, 0, 0, 20, 21, 22, 23, 24, 0, 0, 0, 0, 0, 0, 0, 25, 26, 27, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        -30, 0, 0, -30, 22, -30, -30, 23, -30, -30, -30, -30, -30, 0, 0, 0, 0, 0, 0, 0, 0, -30, -30, -30, 0,
+        -37, 0, -37, 29, -37, -37, 30, -37, -37, -37, -37, -37, 0, 0, 0, 0, 0, 31, 32, -37, -37, -37, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        -1, 0, 0, -1, 0, 25, 26, 0, -1, -1, -1, -1, -1, 0, 0, 0, 0, 0, 0, 0, 0, -1, -1, -1, 0,
+        -3, 0, -3, 0, 34, 35, 0, -3, -3, -3, -3, -3, 0, 0, 0, 0, 0, 0, 0, -3, -3, -3, 0, 0, 0, 0, 0, 0, 0,
         // State 4
-        -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46,
+        -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31,
         // State 5
-        -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9,
+        -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56, -56,
         // State 6
-        -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40,
+        -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11,
         // State 7
-        -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7,
+        -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49,
         // State 8
-        0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10,
+        -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30,
         // State 9
-        -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35,
+        -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9,
         // State 10
-        0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10,
+        0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 15, 0, 0,
         // State 11
-        -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18,
+        -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51,
         // State 12
-        -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24,
+        -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45,
         // State 13
-        -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20,
+        -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18,
         // State 14
-        -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17, -17,
+        -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44,
         // State 15
-        -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25,
+        -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46,
         // State 16
-        -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22,
+        -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19,
         // State 17
-        -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19,
+        0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 15, 0, 0,
         // State 18
-        -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21,
+        -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21,
         // State 19
-        -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23,
+        -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27,
         // State 20
-        0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10,
+        -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23,
         // State 21
-        -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31,
+        -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20,
         // State 22
-        -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32,
+        -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28,
         // State 23
-        0, 0, 9, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 10,
+        -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25,
         // State 24
-        -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38,
+        -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22,
         // State 25
-        -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39,
+        -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24,
         // State 26
-        0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26,
         // State 27
-        -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26,
+        0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 15, 0, 0,
         // State 28
-        -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6,
+        -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38,
         // State 29
-        -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,
+        -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40,
         // State 30
-        -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41,
+        -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39,
+        // State 31
+        -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41,
+        // State 32
+        0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 12, 0, 0, 0, 15, 0, 0,
+        // State 33
+        -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47,
+        // State 34
+        -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48,
+        // State 35
+        0, 0, 40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 36
+        -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29,
+        // State 37
+        -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,
+        // State 38
+        -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10,
+        // State 39
+        -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50,
     ];
     const __EOF_ACTION: &'static [i32] = &[
         0,
         0,
+        -37,
+        -3,
+        -31,
+        -56,
+        -11,
+        -49,
         -30,
-        -1,
-        -46,
         -9,
-        -40,
-        -7,
-        0,
-        -35,
         0,
+        -51,
+        -45,
         -18,
-        -24,
-        -20,
-        -17,
-        -25,
-        -22,
+        -44,
+        -46,
         -19,
+        0,
         -21,
+        -27,
         -23,
-        0,
-        -31,
-        -32,
+        -20,
+        -28,
+        -25,
+        -22,
+        -24,
+        -26,
         0,
         -38,
+        -40,
         -39,
+        -41,
         0,
-        -26,
-        -6,
+        -47,
+        -48,
+        0,
+        -29,
         -8,
-        -41,
+        -10,
+        -50,
     ];
     const __GOTO: &'static [i32] = &[
         // State 0
-        2, 0, 0, 3, 4, 0, 0, 0, 0, 5, 0, 0, 0, 6, 0, 0, 0, 7, 0, 0, 8, 0, 0, 0, 0,
+        0, 0, 2, 0, 0, 3, 4, 0, 0, 5, 0, 6, 0, 0, 0, 0, 7, 0, 0, 0, 8, 9, 0, 10, 0, 0, 0, 0, 0,
         // State 1
-        0, 0, 0, 0, 0, 0, 0, 0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 18, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 2
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 28, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 3
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 33, 0, 0, 0, 0, 0, 0,
         // State 4
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 5
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 6
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 7
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 8
-        27, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 7, 0, 0, 8, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 9
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 10
-        28, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0, 6, 0, 0, 0, 7, 0, 0, 8, 0, 0, 0, 0,
+        0, 0, 36, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 8, 0, 0, 10, 0, 0, 0, 0, 0,
         // State 11
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 12
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 13
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 14
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 15
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 16
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 17
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 37, 0, 0, 3, 4, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 0, 8, 0, 0, 10, 0, 0, 0, 0, 0,
         // State 18
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 19
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 20
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 7, 0, 0, 29, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 21
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 22
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 23
-        0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 30, 0, 0, 0, 7, 0, 0, 8, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 24
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 25
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 26
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 27
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 8, 0, 0, 38, 0, 0, 0, 0, 0,
         // State 28
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 29
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         // State 30
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 31
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 32
+        0, 0, 0, 0, 0, 3, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 39, 0, 0, 0, 8, 0, 0, 10, 0, 0, 0, 0, 0,
+        // State 33
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 34
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 35
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 36
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 37
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 38
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 39
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
     ];
     fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
         const __TERMINAL: &'static [&'static str] = &[
             r###""!=""###,
-            r###""\'.\'""###,
             r###""(""###,
             r###"")""###,
             r###""*""###,
@@ -3271,18 +3860,23 @@ mod __parse__Comparison {
             r###"">=""###,
             r###""False""###,
             r###""True""###,
-            r###""\\\".*\\\"""###,
             r###""and""###,
             r###""not""###,
             r###""or""###,
-            r###""».*«""###,
-            r###""›.‹""###,
+            r###""·""###,
+            r###""÷""###,
             r###""≠""###,
             r###""≤""###,
             r###""≥""###,
+            r###""￼""###,
+            r###""￼￼""###,
+            r###"r#"\"[^\"]*\""#"###,
+            r###"r#"'[^']'"#"###,
             r###"r#"[0-9]+"#"###,
+            r###"r#"»[^«]*«"#"###,
+            r###"r#"›[^‹]‹"#"###,
         ];
-        __ACTION[(__state * 25)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+        __ACTION[(__state * 29)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
             if state == 0 {
                 None
             } else {
@@ -3294,7 +3888,7 @@ mod __parse__Comparison {
         'input,
     >(
         input: &'input str,
-    ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, (usize, &'input str), ()>>
+    ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, (usize, &'input str), (usize, usize, &'static str)>>
     {
         let mut __tokens = super::__intern_token::__Matcher::new(input);
         let mut __states = vec![0_i32];
@@ -3310,31 +3904,35 @@ mod __parse__Comparison {
             };
             __last_location = __lookahead.2.clone();
             __integer = match __lookahead.1 {
-                (1, _) if true => 0,
-                (2, _) if true => 1,
-                (3, _) if true => 2,
-                (4, _) if true => 3,
-                (5, _) if true => 4,
-                (6, _) if true => 5,
-                (7, _) if true => 6,
-                (8, _) if true => 7,
-                (9, _) if true => 8,
-                (10, _) if true => 9,
-                (11, _) if true => 10,
-                (12, _) if true => 11,
-                (13, _) if true => 12,
-                (14, _) if true => 13,
-                (15, _) if true => 14,
-                (16, _) if true => 15,
-                (17, _) if true => 16,
-                (18, _) if true => 17,
-                (19, _) if true => 18,
-                (20, _) if true => 19,
-                (21, _) if true => 20,
-                (22, _) if true => 21,
-                (23, _) if true => 22,
-                (24, _) if true => 23,
+                (5, _) if true => 0,
+                (6, _) if true => 1,
+                (7, _) if true => 2,
+                (8, _) if true => 3,
+                (9, _) if true => 4,
+                (10, _) if true => 5,
+                (11, _) if true => 6,
+                (12, _) if true => 7,
+                (13, _) if true => 8,
+                (14, _) if true => 9,
+                (15, _) if true => 10,
+                (16, _) if true => 11,
+                (17, _) if true => 12,
+                (18, _) if true => 13,
+                (19, _) if true => 14,
+                (20, _) if true => 15,
+                (21, _) if true => 16,
+                (22, _) if true => 17,
+                (23, _) if true => 18,
+                (24, _) if true => 19,
+                (25, _) if true => 20,
+                (26, _) if true => 21,
+                (27, _) if true => 22,
+                (28, _) if true => 23,
                 (0, _) if true => 24,
+                (1, _) if true => 25,
+                (2, _) if true => 26,
+                (3, _) if true => 27,
+                (4, _) if true => 28,
                 _ => {
                     let __state = *__states.last().unwrap() as usize;
                     let __error = __lalrpop_util::ParseError::UnrecognizedToken {
@@ -3346,116 +3944,132 @@ mod __parse__Comparison {
             };
             '__inner: loop {
                 let __state = *__states.last().unwrap() as usize;
-                let __action = __ACTION[__state * 25 + __integer];
+                let __action = __ACTION[__state * 29 + __integer];
                 if __action > 0 {
                     let __symbol = match __integer {
                         0 => match __lookahead.1 {
-                            (1, __tok0) => __Symbol::Term_22_21_3d_22((__tok0)),
+                            (5, __tok0) => __Symbol::Term_22_21_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         1 => match __lookahead.1 {
-                            (2, __tok0) => __Symbol::Term_22_5c_27_2e_5c_27_22((__tok0)),
+                            (6, __tok0) => __Symbol::Term_22_28_22((__tok0)),
                             _ => unreachable!(),
                         },
                         2 => match __lookahead.1 {
-                            (3, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                            (7, __tok0) => __Symbol::Term_22_29_22((__tok0)),
                             _ => unreachable!(),
                         },
                         3 => match __lookahead.1 {
-                            (4, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                            (8, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
                             _ => unreachable!(),
                         },
                         4 => match __lookahead.1 {
-                            (5, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
+                            (9, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
                             _ => unreachable!(),
                         },
                         5 => match __lookahead.1 {
-                            (6, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                            (10, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         6 => match __lookahead.1 {
-                            (7, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
+                            (11, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
                             _ => unreachable!(),
                         },
                         7 => match __lookahead.1 {
-                            (8, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
+                            (12, __tok0) => __Symbol::Term_22_3c_22((__tok0)),
                             _ => unreachable!(),
                         },
                         8 => match __lookahead.1 {
-                            (9, __tok0) => __Symbol::Term_22_3c_22((__tok0)),
+                            (13, __tok0) => __Symbol::Term_22_3c_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         9 => match __lookahead.1 {
-                            (10, __tok0) => __Symbol::Term_22_3c_3d_22((__tok0)),
+                            (14, __tok0) => __Symbol::Term_22_3d_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         10 => match __lookahead.1 {
-                            (11, __tok0) => __Symbol::Term_22_3d_3d_22((__tok0)),
+                            (15, __tok0) => __Symbol::Term_22_3e_22((__tok0)),
                             _ => unreachable!(),
                         },
                         11 => match __lookahead.1 {
-                            (12, __tok0) => __Symbol::Term_22_3e_22((__tok0)),
+                            (16, __tok0) => __Symbol::Term_22_3e_3d_22((__tok0)),
                             _ => unreachable!(),
                         },
                         12 => match __lookahead.1 {
-                            (13, __tok0) => __Symbol::Term_22_3e_3d_22((__tok0)),
+                            (17, __tok0) => __Symbol::Term_22False_22((__tok0)),
                             _ => unreachable!(),
                         },
                         13 => match __lookahead.1 {
-                            (14, __tok0) => __Symbol::Term_22False_22((__tok0)),
+                            (18, __tok0) => __Symbol::Term_22True_22((__tok0)),
                             _ => unreachable!(),
                         },
                         14 => match __lookahead.1 {
-                            (15, __tok0) => __Symbol::Term_22True_22((__tok0)),
+                            (19, __tok0) => __Symbol::Term_22and_22((__tok0)),
                             _ => unreachable!(),
                         },
                         15 => match __lookahead.1 {
-                            (16, __tok0) => __Symbol::Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22((__tok0)),
+                            (20, __tok0) => __Symbol::Term_22not_22((__tok0)),
                             _ => unreachable!(),
                         },
                         16 => match __lookahead.1 {
-                            (17, __tok0) => __Symbol::Term_22and_22((__tok0)),
+                            (21, __tok0) => __Symbol::Term_22or_22((__tok0)),
                             _ => unreachable!(),
                         },
                         17 => match __lookahead.1 {
-                            (18, __tok0) => __Symbol::Term_22not_22((__tok0)),
+                            (22, __tok0) => __Symbol::Term_22_b7_22((__tok0)),
                             _ => unreachable!(),
                         },
                         18 => match __lookahead.1 {
-                            (19, __tok0) => __Symbol::Term_22or_22((__tok0)),
+                            (23, __tok0) => __Symbol::Term_22_f7_22((__tok0)),
                             _ => unreachable!(),
                         },
                         19 => match __lookahead.1 {
-                            (20, __tok0) => __Symbol::Term_22_bb_2e_2a_ab_22((__tok0)),
+                            (24, __tok0) => __Symbol::Term_22_2260_22((__tok0)),
                             _ => unreachable!(),
                         },
                         20 => match __lookahead.1 {
-                            (21, __tok0) => __Symbol::Term_22_203a_2e_2039_22((__tok0)),
+                            (25, __tok0) => __Symbol::Term_22_2264_22((__tok0)),
                             _ => unreachable!(),
                         },
                         21 => match __lookahead.1 {
-                            (22, __tok0) => __Symbol::Term_22_2260_22((__tok0)),
+                            (26, __tok0) => __Symbol::Term_22_2265_22((__tok0)),
                             _ => unreachable!(),
                         },
                         22 => match __lookahead.1 {
-                            (23, __tok0) => __Symbol::Term_22_2264_22((__tok0)),
+                            (27, __tok0) => __Symbol::Term_22_fffc_22((__tok0)),
                             _ => unreachable!(),
                         },
                         23 => match __lookahead.1 {
-                            (24, __tok0) => __Symbol::Term_22_2265_22((__tok0)),
+                            (28, __tok0) => __Symbol::Term_22_fffc_fffc_22((__tok0)),
                             _ => unreachable!(),
                         },
                         24 => match __lookahead.1 {
-                            (0, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            (0, __tok0) => __Symbol::Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23((__tok0)),
                             _ => unreachable!(),
                         },
-                        _ => unreachable!(),
-                    };
-                    __states.push(__action - 1);
-                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
-                    continue '__shift;
-                } else if __action < 0 {
-                    if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        25 => match __lookahead.1 {
+                            (1, __tok0) => __Symbol::Termr_23_22_27_5b_5e_27_5d_27_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        26 => match __lookahead.1 {
+                            (2, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        27 => match __lookahead.1 {
+                            (3, __tok0) => __Symbol::Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        28 => match __lookahead.1 {
+                            (4, __tok0) => __Symbol::Termr_23_22_203a_5b_5e_2039_5d_2039_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
                         return r;
                     }
                 } else {
@@ -3494,531 +4108,2549 @@ mod __parse__Comparison {
         __states: &mut ::std::vec::Vec<i32>,
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
         _: ::std::marker::PhantomData<()>,
-    ) -> Option<Result<Box<Expr>,__lalrpop_util::ParseError<usize, (usize, &'input str), ()>>>
+    ) -> Option<Result<Box<Expr>,__lalrpop_util::ParseError<usize, (usize, &'input str), (usize, usize, &'static str)>>>
     {
         let __nonterminal = match -__action {
             1 => {
-                // ArithmeticExpr = BinExprTier<SummandOp, Factor> => ActionFn(23);
+                // @L =  => ActionFn(46);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action46::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40L(__nt), __end));
+                0
+            }
+            2 => {
+                // @R =  => ActionFn(45);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action45::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40R(__nt), __end));
+                1
+            }
+            3 => {
+                // ArithmeticExpr = BinExprTier<SummandOp, Factor> => ActionFn(29);
                 let __sym0 = __pop_NtBinExprTier_3cSummandOp_2c_20Factor_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action23::<>(input, __sym0);
+                let __nt = super::__action29::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtArithmeticExpr(__nt), __end));
-                0
+                2
             }
-            2 => {
-                // BinExprTier<ConOp, Negation> = BinExprTier<ConOp, Negation>, ConOp, Negation => ActionFn(42);
+            4 => {
+                // BinExprTier<ConOp, Negation> = BinExprTier<ConOp, Negation>, ConOp, Negation => ActionFn(53);
                 let __sym2 = __pop_NtNegation(__symbols);
                 let __sym1 = __pop_NtConOp(__symbols);
                 let __sym0 = __pop_NtBinExprTier_3cConOp_2c_20Negation_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action42::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action53::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cConOp_2c_20Negation_3e(__nt), __end));
-                1
+                3
             }
-            3 => {
-                // BinExprTier<ConOp, Negation> = Negation => ActionFn(43);
+            5 => {
+                // BinExprTier<ConOp, Negation> = Negation => ActionFn(54);
                 let __sym0 = __pop_NtNegation(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action43::<>(input, __sym0);
+                let __nt = super::__action54::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cConOp_2c_20Negation_3e(__nt), __end));
-                1
+                3
             }
-            4 => {
-                // BinExprTier<DisOp, Conjunction> = BinExprTier<DisOp, Conjunction>, DisOp, Conjunction => ActionFn(44);
+            6 => {
+                // BinExprTier<DisOp, Conjunction> = BinExprTier<DisOp, Conjunction>, DisOp, Conjunction => ActionFn(55);
                 let __sym2 = __pop_NtConjunction(__symbols);
                 let __sym1 = __pop_NtDisOp(__symbols);
                 let __sym0 = __pop_NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action44::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action55::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__nt), __end));
-                2
+                4
             }
-            5 => {
-                // BinExprTier<DisOp, Conjunction> = Conjunction => ActionFn(45);
+            7 => {
+                // BinExprTier<DisOp, Conjunction> = Conjunction => ActionFn(56);
                 let __sym0 = __pop_NtConjunction(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action45::<>(input, __sym0);
+                let __nt = super::__action56::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__nt), __end));
-                2
+                4
             }
-            6 => {
-                // BinExprTier<FactorOp, Term> = BinExprTier<FactorOp, Term>, FactorOp, Term => ActionFn(36);
+            8 => {
+                // BinExprTier<FactorOp, Term> = BinExprTier<FactorOp, Term>, FactorOp, Term => ActionFn(47);
                 let __sym2 = __pop_NtTerm(__symbols);
                 let __sym1 = __pop_NtFactorOp(__symbols);
                 let __sym0 = __pop_NtBinExprTier_3cFactorOp_2c_20Term_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action36::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action47::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cFactorOp_2c_20Term_3e(__nt), __end));
-                3
+                5
             }
-            7 => {
-                // BinExprTier<FactorOp, Term> = Term => ActionFn(37);
+            9 => {
+                // BinExprTier<FactorOp, Term> = Term => ActionFn(48);
                 let __sym0 = __pop_NtTerm(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action37::<>(input, __sym0);
+                let __nt = super::__action48::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cFactorOp_2c_20Term_3e(__nt), __end));
-                3
+                5
             }
-            8 => {
-                // BinExprTier<SummandOp, Factor> = BinExprTier<SummandOp, Factor>, SummandOp, Factor => ActionFn(38);
+            10 => {
+                // BinExprTier<SummandOp, Factor> = BinExprTier<SummandOp, Factor>, SummandOp, Factor => ActionFn(49);
                 let __sym2 = __pop_NtFactor(__symbols);
                 let __sym1 = __pop_NtSummandOp(__symbols);
                 let __sym0 = __pop_NtBinExprTier_3cSummandOp_2c_20Factor_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action38::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action49::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cSummandOp_2c_20Factor_3e(__nt), __end));
-                4
+                6
             }
-            9 => {
-                // BinExprTier<SummandOp, Factor> = Factor => ActionFn(39);
+            11 => {
+                // BinExprTier<SummandOp, Factor> = Factor => ActionFn(50);
                 let __sym0 = __pop_NtFactor(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action39::<>(input, __sym0);
+                let __nt = super::__action50::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBinExprTier_3cSummandOp_2c_20Factor_3e(__nt), __end));
-                4
+                6
             }
-            10 => {
-                // Boolean = "True" => ActionFn(6);
+            12 => {
+                // Boolean = "True" => ActionFn(9);
                 let __sym0 = __pop_Term_22True_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action6::<>(input, __sym0);
+                let __nt = super::__action9::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
-                5
+                7
             }
-            11 => {
-                // Boolean = "False" => ActionFn(7);
+            13 => {
+                // Boolean = "False" => ActionFn(10);
                 let __sym0 = __pop_Term_22False_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action7::<>(input, __sym0);
+                let __nt = super::__action10::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
-                5
+                7
             }
-            12 => {
-                // Boolean = "(", BooleanExpr, ")" => ActionFn(8);
+            14 => {
+                // Boolean = "(", BooleanExpr, ")" => ActionFn(11);
                 let __sym2 = __pop_Term_22_29_22(__symbols);
                 let __sym1 = __pop_NtBooleanExpr(__symbols);
                 let __sym0 = __pop_Term_22_28_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action8::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action11::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
-                5
+                7
             }
-            13 => {
-                // Boolean = Comparison => ActionFn(9);
+            15 => {
+                // Boolean = Comparison => ActionFn(12);
                 let __sym0 = __pop_NtComparison(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action9::<>(input, __sym0);
+                let __nt = super::__action12::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
-                5
+                7
             }
-            14 => {
-                // BooleanExpr = BinExprTier<DisOp, Conjunction> => ActionFn(3);
+            16 => {
+                // Boolean = "￼￼" => ActionFn(13);
+                let __sym0 = __pop_Term_22_fffc_fffc_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action13::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
+                7
+            }
+            17 => {
+                // BooleanExpr = BinExprTier<DisOp, Conjunction> => ActionFn(6);
                 let __sym0 = __pop_NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action3::<>(input, __sym0);
+                let __nt = super::__action6::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtBooleanExpr(__nt), __end));
-                6
+                8
             }
-            15 => {
-                // Char = "\'.\'" => ActionFn(34);
-                let __sym0 = __pop_Term_22_5c_27_2e_5c_27_22(__symbols);
+            18 => {
+                // Char = r#"'[^']'"# => ActionFn(43);
+                let __sym0 = __pop_Termr_23_22_27_5b_5e_27_5d_27_22_23(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action34::<>(input, __sym0);
+                let __nt = super::__action43::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtChar(__nt), __end));
-                7
+                9
             }
-            16 => {
-                // Char = "›.‹" => ActionFn(35);
-                let __sym0 = __pop_Term_22_203a_2e_2039_22(__symbols);
+            19 => {
+                // Char = r#"›[^‹]‹"# => ActionFn(44);
+                let __sym0 = __pop_Termr_23_22_203a_5b_5e_2039_5d_2039_22_23(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action35::<>(input, __sym0);
+                let __nt = super::__action44::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtChar(__nt), __end));
-                7
+                9
             }
-            17 => {
-                // CompOp = "==" => ActionFn(14);
+            20 => {
+                // CompOp = "==" => ActionFn(20);
                 let __sym0 = __pop_Term_22_3d_3d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action14::<>(input, __sym0);
+                let __nt = super::__action20::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            18 => {
-                // CompOp = "!=" => ActionFn(15);
+            21 => {
+                // CompOp = "!=" => ActionFn(21);
                 let __sym0 = __pop_Term_22_21_3d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action15::<>(input, __sym0);
+                let __nt = super::__action21::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            19 => {
-                // CompOp = "≠" => ActionFn(16);
+            22 => {
+                // CompOp = "≠" => ActionFn(22);
                 let __sym0 = __pop_Term_22_2260_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action16::<>(input, __sym0);
+                let __nt = super::__action22::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            20 => {
-                // CompOp = "<=" => ActionFn(17);
+            23 => {
+                // CompOp = "<=" => ActionFn(23);
                 let __sym0 = __pop_Term_22_3c_3d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action17::<>(input, __sym0);
+                let __nt = super::__action23::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            21 => {
-                // CompOp = "≤" => ActionFn(18);
+            24 => {
+                // CompOp = "≤" => ActionFn(24);
                 let __sym0 = __pop_Term_22_2264_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action18::<>(input, __sym0);
+                let __nt = super::__action24::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            22 => {
-                // CompOp = ">=" => ActionFn(19);
+            25 => {
+                // CompOp = ">=" => ActionFn(25);
                 let __sym0 = __pop_Term_22_3e_3d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action19::<>(input, __sym0);
+                let __nt = super::__action25::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            23 => {
-                // CompOp = "≥" => ActionFn(20);
+            26 => {
+                // CompOp = "≥" => ActionFn(26);
                 let __sym0 = __pop_Term_22_2265_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action20::<>(input, __sym0);
+                let __nt = super::__action26::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            24 => {
-                // CompOp = "<" => ActionFn(21);
+            27 => {
+                // CompOp = "<" => ActionFn(27);
                 let __sym0 = __pop_Term_22_3c_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action21::<>(input, __sym0);
+                let __nt = super::__action27::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            25 => {
-                // CompOp = ">" => ActionFn(22);
+            28 => {
+                // CompOp = ">" => ActionFn(28);
                 let __sym0 = __pop_Term_22_3e_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action22::<>(input, __sym0);
+                let __nt = super::__action28::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
-                8
+                10
             }
-            26 => {
-                // Comparison = ArithmeticExpr, CompOp, ArithmeticExpr => ActionFn(13);
+            29 => {
+                // Comparison = ArithmeticExpr, CompOp, ArithmeticExpr => ActionFn(17);
                 let __sym2 = __pop_NtArithmeticExpr(__symbols);
                 let __sym1 = __pop_NtCompOp(__symbols);
                 let __sym0 = __pop_NtArithmeticExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action13::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action17::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtComparison(__nt), __end));
-                9
+                11
             }
-            27 => {
-                // ConOp = "and" => ActionFn(11);
-                let __sym0 = __pop_Term_22and_22(__symbols);
+            30 => {
+                // Comparison = Str => ActionFn(18);
+                let __sym0 = __pop_NtStr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action11::<>(input, __sym0);
+                let __nt = super::__action18::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtConOp(__nt), __end));
-                10
+                __symbols.push((__start, __Symbol::NtComparison(__nt), __end));
+                11
             }
-            28 => {
-                // Conjunction = BinExprTier<ConOp, Negation> => ActionFn(4);
-                let __sym0 = __pop_NtBinExprTier_3cConOp_2c_20Negation_3e(__symbols);
+            31 => {
+                // Comparison = Char => ActionFn(19);
+                let __sym0 = __pop_NtChar(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action4::<>(input, __sym0);
+                let __nt = super::__action19::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtConjunction(__nt), __end));
+                __symbols.push((__start, __Symbol::NtComparison(__nt), __end));
                 11
             }
-            29 => {
-                // DisOp = "or" => ActionFn(10);
-                let __sym0 = __pop_Term_22or_22(__symbols);
+            32 => {
+                // ConOp = "and" => ActionFn(15);
+                let __sym0 = __pop_Term_22and_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action10::<>(input, __sym0);
+                let __nt = super::__action15::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtDisOp(__nt), __end));
+                __symbols.push((__start, __Symbol::NtConOp(__nt), __end));
                 12
             }
-            30 => {
-                // Factor = BinExprTier<FactorOp, Term> => ActionFn(24);
-                let __sym0 = __pop_NtBinExprTier_3cFactorOp_2c_20Term_3e(__symbols);
+            33 => {
+                // Conjunction = BinExprTier<ConOp, Negation> => ActionFn(7);
+                let __sym0 = __pop_NtBinExprTier_3cConOp_2c_20Negation_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action24::<>(input, __sym0);
+                let __nt = super::__action7::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                __symbols.push((__start, __Symbol::NtConjunction(__nt), __end));
                 13
             }
-            31 => {
-                // FactorOp = "*" => ActionFn(30);
-                let __sym0 = __pop_Term_22_2a_22(__symbols);
+            34 => {
+                // DisOp = "or" => ActionFn(14);
+                let __sym0 = __pop_Term_22or_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action30::<>(input, __sym0);
+                let __nt = super::__action14::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                __symbols.push((__start, __Symbol::NtDisOp(__nt), __end));
                 14
             }
-            32 => {
-                // FactorOp = "/" => ActionFn(31);
-                let __sym0 = __pop_Term_22_2f_22(__symbols);
+            35 => {
+                // Expr = BooleanExpr => ActionFn(4);
+                let __sym0 = __pop_NtBooleanExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action31::<>(input, __sym0);
+                let __nt = super::__action4::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
-                14
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                15
             }
-            33 => {
-                // NegOp = "not" => ActionFn(12);
-                let __sym0 = __pop_Term_22not_22(__symbols);
+            36 => {
+                // Expr = ArithmeticExpr => ActionFn(5);
+                let __sym0 = __pop_NtArithmeticExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action12::<>(input, __sym0);
+                let __nt = super::__action5::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtNegOp(__nt), __end));
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
                 15
             }
-            34 => {
-                // Negation = UnExprTier<NegOp, Boolean> => ActionFn(5);
-                let __sym0 = __pop_NtUnExprTier_3cNegOp_2c_20Boolean_3e(__symbols);
+            37 => {
+                // Factor = BinExprTier<FactorOp, Term> => ActionFn(30);
+                let __sym0 = __pop_NtBinExprTier_3cFactorOp_2c_20Term_3e(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action5::<>(input, __sym0);
+                let __nt = super::__action30::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtNegation(__nt), __end));
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
                 16
             }
-            35 => {
-                // Num = r#"[0-9]+"# => ActionFn(27);
-                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+            38 => {
+                // FactorOp = "*" => ActionFn(37);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action27::<>(input, __sym0);
+                let __nt = super::__action37::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
                 17
             }
-            36 => {
-                // Str = "\\\".*\\\"" => ActionFn(32);
-                let __sym0 = __pop_Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22(__symbols);
+            39 => {
+                // FactorOp = "·" => ActionFn(38);
+                let __sym0 = __pop_Term_22_b7_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action32::<>(input, __sym0);
+                let __nt = super::__action38::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
-                18
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                17
             }
-            37 => {
-                // Str = "».*«" => ActionFn(33);
-                let __sym0 = __pop_Term_22_bb_2e_2a_ab_22(__symbols);
+            40 => {
+                // FactorOp = "/" => ActionFn(39);
+                let __sym0 = __pop_Term_22_2f_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action33::<>(input, __sym0);
+                let __nt = super::__action39::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
-                18
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                17
             }
-            38 => {
-                // SummandOp = "+" => ActionFn(28);
-                let __sym0 = __pop_Term_22_2b_22(__symbols);
+            41 => {
+                // FactorOp = "÷" => ActionFn(40);
+                let __sym0 = __pop_Term_22_f7_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action28::<>(input, __sym0);
+                let __nt = super::__action40::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
-                __symbols.push((__start, __Symbol::NtSummandOp(__nt), __end));
-                19
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                17
+            }
+            42 => {
+                // NegOp = "not" => ActionFn(16);
+                let __sym0 = __pop_Term_22not_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action16::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNegOp(__nt), __end));
+                18
+            }
+            43 => {
+                // Negation = UnExprTier<NegOp, Boolean> => ActionFn(8);
+                let __sym0 = __pop_NtUnExprTier_3cNegOp_2c_20Boolean_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action8::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNegation(__nt), __end));
+                19
+            }
+            44 => {
+                // Num = r#"[0-9]+"# => ActionFn(58);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = match super::__action58::<>(input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+                20
+            }
+            45 => {
+                // Str = r#"\"[^\"]*\""# => ActionFn(41);
+                let __sym0 = __pop_Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action41::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
+                21
+            }
+            46 => {
+                // Str = r#"»[^«]*«"# => ActionFn(42);
+                let __sym0 = __pop_Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action42::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
+                21
+            }
+            47 => {
+                // SummandOp = "+" => ActionFn(35);
+                let __sym0 = __pop_Term_22_2b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action35::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSummandOp(__nt), __end));
+                22
+            }
+            48 => {
+                // SummandOp = "-" => ActionFn(36);
+                let __sym0 = __pop_Term_22_2d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action36::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSummandOp(__nt), __end));
+                22
+            }
+            49 => {
+                // Term = Num => ActionFn(31);
+                let __sym0 = __pop_NtNum(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action31::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                23
+            }
+            50 => {
+                // Term = "(", ArithmeticExpr, ")" => ActionFn(32);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtArithmeticExpr(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action32::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                23
+            }
+            51 => {
+                // Term = "￼" => ActionFn(33);
+                let __sym0 = __pop_Term_22_fffc_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action33::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                23
+            }
+            52 => {
+                // UnExprTier<NegOp, Boolean> = NegOp, Boolean => ActionFn(51);
+                let __sym1 = __pop_NtBoolean(__symbols);
+                let __sym0 = __pop_NtNegOp(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym1.2.clone();
+                let __nt = super::__action51::<>(input, __sym0, __sym1);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 2);
+                __symbols.push((__start, __Symbol::NtUnExprTier_3cNegOp_2c_20Boolean_3e(__nt), __end));
+                24
+            }
+            53 => {
+                // UnExprTier<NegOp, Boolean> = Boolean => ActionFn(52);
+                let __sym0 = __pop_NtBoolean(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action52::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtUnExprTier_3cNegOp_2c_20Boolean_3e(__nt), __end));
+                24
+            }
+            54 => {
+                // __ArithmeticExpr = ArithmeticExpr => ActionFn(3);
+                let __sym0 = __pop_NtArithmeticExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action3::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt____ArithmeticExpr(__nt), __end));
+                25
+            }
+            55 => {
+                // __BooleanExpr = BooleanExpr => ActionFn(1);
+                let __sym0 = __pop_NtBooleanExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action1::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt____BooleanExpr(__nt), __end));
+                26
+            }
+            56 => {
+                // __Comparison = Comparison => ActionFn(2);
+                let __sym0 = __pop_NtComparison(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action2::<>(input, __sym0);
+                return Some(Ok(__nt));
+            }
+            57 => {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt____Expr(__nt), __end));
+                28
+            }
+            _ => panic!("invalid action code {}", __action)
+        };
+        let __state = *__states.last().unwrap() as usize;
+        let __next_state = __GOTO[__state * 29 + __nonterminal] - 1;
+        __states.push(__next_state);
+        None
+    }
+    fn __pop_Term_22_21_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_21_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_28_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_28_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_29_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_29_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2a_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2a_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2b_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2b_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2f_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3c_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3c_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3d_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3d_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3e_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3e_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22False_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22False_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22True_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22True_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22and_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22and_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22not_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22not_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22or_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22or_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_b7_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_b7_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_f7_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_f7_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2260_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2260_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2264_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2264_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_2265_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2265_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_fffc_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_fffc_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_fffc_fffc_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_fffc_fffc_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_27_5b_5e_27_5d_27_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_27_5b_5e_27_5d_27_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Termr_23_22_203a_5b_5e_2039_5d_2039_22_23<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Termr_23_22_203a_5b_5e_2039_5d_2039_22_23(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40L<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40L(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt_40R<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, usize, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt_40R(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtArithmeticExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtArithmeticExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBinExprTier_3cConOp_2c_20Negation_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBinExprTier_3cConOp_2c_20Negation_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBinExprTier_3cDisOp_2c_20Conjunction_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBinExprTier_3cFactorOp_2c_20Term_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBinExprTier_3cFactorOp_2c_20Term_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBinExprTier_3cSummandOp_2c_20Factor_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBinExprTier_3cSummandOp_2c_20Factor_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBoolean<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBoolean(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtBooleanExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtBooleanExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtChar<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, char, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtChar(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtCompOp<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Opcode, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtCompOp(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtComparison<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtComparison(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtConOp<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Opcode, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtConOp(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtConjunction<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtConjunction(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtDisOp<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Opcode, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtDisOp(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtFactor<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtFactor(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtFactorOp<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Opcode, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtFactorOp(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtNegOp<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Opcode, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtNegOp(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtNegation<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtNegation(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtNum<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, i32, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtNum(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtStr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, String, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtStr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtSummandOp<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Opcode, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtSummandOp(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtTerm<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtTerm(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_NtUnExprTier_3cNegOp_2c_20Boolean_3e<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtUnExprTier_3cNegOp_2c_20Boolean_3e(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____ArithmeticExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____ArithmeticExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____BooleanExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____BooleanExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Comparison<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Comparison(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Nt____Expr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+}
+pub use self::__parse__Comparison::parse_Comparison;
+
+mod __parse__Expr {
+    #![allow(non_snake_case, non_camel_case_types, unused_mut, unused_variables, unused_imports)]
+
+    use std::str::FromStr;
+    use ast::{Expr, Opcode};
+    use lalrpop_util::ParseError;
+    extern crate lalrpop_util as __lalrpop_util;
+    #[allow(dead_code)]
+    pub enum __Symbol<'input> {
+        Term_22_21_3d_22(&'input str),
+        Term_22_28_22(&'input str),
+        Term_22_29_22(&'input str),
+        Term_22_2a_22(&'input str),
+        Term_22_2b_22(&'input str),
+        Term_22_2d_22(&'input str),
+        Term_22_2f_22(&'input str),
+        Term_22_3c_22(&'input str),
+        Term_22_3c_3d_22(&'input str),
+        Term_22_3d_3d_22(&'input str),
+        Term_22_3e_22(&'input str),
+        Term_22_3e_3d_22(&'input str),
+        Term_22False_22(&'input str),
+        Term_22True_22(&'input str),
+        Term_22and_22(&'input str),
+        Term_22not_22(&'input str),
+        Term_22or_22(&'input str),
+        Term_22_b7_22(&'input str),
+        Term_22_f7_22(&'input str),
+        Term_22_2260_22(&'input str),
+        Term_22_2264_22(&'input str),
+        Term_22_2265_22(&'input str),
+        Term_22_fffc_22(&'input str),
+        Term_22_fffc_fffc_22(&'input str),
+        Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23(&'input str),
+        Termr_23_22_27_5b_5e_27_5d_27_22_23(&'input str),
+        Termr_23_22_5b0_2d9_5d_2b_22_23(&'input str),
+        Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23(&'input str),
+        Termr_23_22_203a_5b_5e_2039_5d_2039_22_23(&'input str),
+        Nt_40L(usize),
+        Nt_40R(usize),
+        NtArithmeticExpr(Box<Expr>),
+        NtBinExprTier_3cConOp_2c_20Negation_3e(Box<Expr>),
+        NtBinExprTier_3cDisOp_2c_20Conjunction_3e(Box<Expr>),
+        NtBinExprTier_3cFactorOp_2c_20Term_3e(Box<Expr>),
+        NtBinExprTier_3cSummandOp_2c_20Factor_3e(Box<Expr>),
+        NtBoolean(Box<Expr>),
+        NtBooleanExpr(Box<Expr>),
+        NtChar(char),
+        NtCompOp(Opcode),
+        NtComparison(Box<Expr>),
+        NtConOp(Opcode),
+        NtConjunction(Box<Expr>),
+        NtDisOp(Opcode),
+        NtExpr(Box<Expr>),
+        NtFactor(Box<Expr>),
+        NtFactorOp(Opcode),
+        NtNegOp(Opcode),
+        NtNegation(Box<Expr>),
+        NtNum(i32),
+        NtStr(String),
+        NtSummandOp(Opcode),
+        NtTerm(Box<Expr>),
+        NtUnExprTier_3cNegOp_2c_20Boolean_3e(Box<Expr>),
+        Nt____ArithmeticExpr(Box<Expr>),
+        Nt____BooleanExpr(Box<Expr>),
+        Nt____Comparison(Box<Expr>),
+        Nt____Expr(Box<Expr>),
+    }
+    const __ACTION: &'static [i32] = &[
+        // State 0
+        0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 22, 0, 23, 0, 0, 0, 0, 0, 0, 24, 25, 26, 27, 28, 29, 30,
+        // State 1
+        32, 0, 0, 0, 0, 0, 0, 33, 34, 35, 36, 37, 0, 0, 0, 0, 0, 0, 0, 38, 39, 40, 0, 0, 0, 0, 0, 0, 0,
+        // State 2
+        0, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 42, 0, -33, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 3
+        0, 0, -17, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 4
+        -37, 0, -37, 46, -37, -37, 47, -37, -37, -37, -37, -37, 0, 0, -37, 0, -37, 48, 49, -37, -37, -37, 0, 0, 0, 0, 0, 0, 0,
+        // State 5
+        -3, 0, -3, 0, 51, 52, 0, -3, -3, -3, -3, -3, 0, 0, -3, 0, -3, 0, 0, -3, -3, -3, 0, 0, 0, 0, 0, 0, 0,
+        // State 6
+        -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53, -53,
+        // State 7
+        -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35, -35,
+        // State 8
+        -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31, -31,
+        // State 9
+        -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15, -15,
+        // State 10
+        -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7, -7,
+        // State 11
+        -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57, -57,
+        // State 12
+        -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11, -11,
+        // State 13
+        0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 22, 0, 0, 0, 0, 0, 0, 0, 0, 24, 25, 26, 27, 28, 29, 30,
+        // State 14
+        -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5, -5,
+        // State 15
+        -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49, -49,
+        // State 16
+        -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30, -30,
+        // State 17
+        -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9, -9,
+        // State 18
+        -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43, -43,
+        // State 19
+        0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 22, 0, 23, 0, 0, 0, 0, 0, 0, 24, 25, 26, 27, 28, 29, 30,
+        // State 20
+        -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13, -13,
+        // State 21
+        -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12, -12,
+        // State 22
+        -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42, -42,
+        // State 23
+        -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51, -51,
+        // State 24
+        -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16, -16,
+        // State 25
+        -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45, -45,
+        // State 26
+        -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18, -18,
+        // State 27
+        -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44, -44,
+        // State 28
+        -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46, -46,
+        // State 29
+        -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19, -19,
+        // State 30
+        0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0,
+        // State 31
+        -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21, -21,
+        // State 32
+        -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27, -27,
+        // State 33
+        -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23, -23,
+        // State 34
+        -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20, -20,
+        // State 35
+        -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28, -28,
+        // State 36
+        -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25, -25,
+        // State 37
+        -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22, -22,
+        // State 38
+        -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24, -24,
+        // State 39
+        -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26, -26,
+        // State 40
+        0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 22, 0, 23, 0, 0, 0, 0, 0, 0, 24, 25, 26, 27, 28, 29, 30,
+        // State 41
+        -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32, -32,
+        // State 42
+        0, 20, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 21, 22, 0, 23, 0, 0, 0, 0, 0, 0, 24, 25, 26, 27, 28, 29, 30,
+        // State 43
+        -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34, -34,
+        // State 44
+        0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0,
+        // State 45
+        -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38, -38,
+        // State 46
+        -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40, -40,
+        // State 47
+        -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39, -39,
+        // State 48
+        -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41, -41,
+        // State 49
+        0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0,
+        // State 50
+        -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47, -47,
+        // State 51
+        -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48, -48,
+        // State 52
+        32, 0, 0, 0, 0, 0, 0, 33, 34, 35, 36, 37, 0, 0, 0, 0, 0, 0, 0, 38, 39, 40, 0, 0, 0, 0, 0, 0, 0,
+        // State 53
+        -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52, -52,
+        // State 54
+        32, 0, 63, 0, 0, 0, 0, 33, 34, 35, 36, 37, 0, 0, 0, 0, 0, 0, 0, 38, 39, 40, 0, 0, 0, 0, 0, 0, 0,
+        // State 55
+        0, 0, 64, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 56
+        -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29, -29,
+        // State 57
+        0, 58, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 24, 0, 0, 0, 28, 0, 0,
+        // State 58
+        -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4, -4,
+        // State 59
+        -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6, -6,
+        // State 60
+        -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8, -8,
+        // State 61
+        -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10, -10,
+        // State 62
+        -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50, -50,
+        // State 63
+        -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14, -14,
+        // State 64
+        0, 0, 63, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    const __EOF_ACTION: &'static [i32] = &[
+        0,
+        -36,
+        -33,
+        -17,
+        -37,
+        -3,
+        -53,
+        -35,
+        -31,
+        -15,
+        -7,
+        -57,
+        -11,
+        0,
+        -5,
+        -49,
+        -30,
+        -9,
+        -43,
+        0,
+        -13,
+        -12,
+        -42,
+        -51,
+        -16,
+        -45,
+        -18,
+        -44,
+        -46,
+        -19,
+        0,
+        -21,
+        -27,
+        -23,
+        -20,
+        -28,
+        -25,
+        -22,
+        -24,
+        -26,
+        0,
+        -32,
+        0,
+        -34,
+        0,
+        -38,
+        -40,
+        -39,
+        -41,
+        0,
+        -47,
+        -48,
+        0,
+        -52,
+        0,
+        0,
+        -29,
+        0,
+        -4,
+        -6,
+        -8,
+        -10,
+        -50,
+        -14,
+        0,
+    ];
+    const __GOTO: &'static [i32] = &[
+        // State 0
+        0, 0, 2, 3, 4, 5, 6, 7, 8, 9, 0, 10, 0, 11, 0, 12, 13, 0, 14, 15, 16, 17, 0, 18, 19, 0, 0, 0, 0,
+        // State 1
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 2
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 41, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 3
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 43, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 4
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 45, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 5
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 50, 0, 0, 0, 0, 0, 0,
+        // State 6
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 7
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 8
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 9
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 10
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 11
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 12
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 13
+        0, 0, 53, 0, 0, 5, 6, 54, 0, 9, 0, 10, 0, 0, 0, 0, 13, 0, 0, 0, 16, 17, 0, 18, 0, 0, 0, 0, 0,
+        // State 14
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 15
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 16
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 17
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 18
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 19
+        0, 0, 55, 3, 4, 5, 6, 7, 56, 9, 0, 10, 0, 11, 0, 0, 13, 0, 14, 15, 16, 17, 0, 18, 19, 0, 0, 0, 0,
+        // State 20
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 21
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 22
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 23
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 24
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 25
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 26
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 27
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 28
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 29
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 30
+        0, 0, 57, 0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 16, 0, 0, 18, 0, 0, 0, 0, 0,
+        // State 31
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 32
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 33
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 34
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 35
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 36
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 37
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 38
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 39
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 40
+        0, 0, 53, 0, 0, 5, 6, 7, 0, 9, 0, 10, 0, 0, 0, 0, 13, 0, 14, 59, 16, 17, 0, 18, 19, 0, 0, 0, 0,
+        // State 41
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 42
+        0, 0, 53, 3, 0, 5, 6, 7, 0, 9, 0, 10, 0, 60, 0, 0, 13, 0, 14, 15, 16, 17, 0, 18, 19, 0, 0, 0, 0,
+        // State 43
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 44
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 16, 0, 0, 61, 0, 0, 0, 0, 0,
+        // State 45
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 46
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 47
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 48
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 49
+        0, 0, 0, 0, 0, 5, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 62, 0, 0, 0, 16, 0, 0, 18, 0, 0, 0, 0, 0,
+        // State 50
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 51
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 52
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 53
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 54
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 31, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 55
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 56
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 57
+        0, 0, 65, 0, 0, 5, 6, 0, 0, 0, 0, 0, 0, 0, 0, 0, 13, 0, 0, 0, 16, 0, 0, 18, 0, 0, 0, 0, 0,
+        // State 58
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 59
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 60
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 61
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 62
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 63
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        // State 64
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+    ];
+    fn __expected_tokens(__state: usize) -> Vec<::std::string::String> {
+        const __TERMINAL: &'static [&'static str] = &[
+            r###""!=""###,
+            r###""(""###,
+            r###"")""###,
+            r###""*""###,
+            r###""+""###,
+            r###""-""###,
+            r###""/""###,
+            r###""<""###,
+            r###""<=""###,
+            r###""==""###,
+            r###"">""###,
+            r###"">=""###,
+            r###""False""###,
+            r###""True""###,
+            r###""and""###,
+            r###""not""###,
+            r###""or""###,
+            r###""·""###,
+            r###""÷""###,
+            r###""≠""###,
+            r###""≤""###,
+            r###""≥""###,
+            r###""￼""###,
+            r###""￼￼""###,
+            r###"r#"\"[^\"]*\""#"###,
+            r###"r#"'[^']'"#"###,
+            r###"r#"[0-9]+"#"###,
+            r###"r#"»[^«]*«"#"###,
+            r###"r#"›[^‹]‹"#"###,
+        ];
+        __ACTION[(__state * 29)..].iter().zip(__TERMINAL).filter_map(|(&state, terminal)| {
+            if state == 0 {
+                None
+            } else {
+                Some(terminal.to_string())
+            }
+        }).collect()
+    }
+    pub fn parse_Expr<
+        'input,
+    >(
+        input: &'input str,
+    ) -> Result<Box<Expr>, __lalrpop_util::ParseError<usize, (usize, &'input str), (usize, usize, &'static str)>>
+    {
+        let mut __tokens = super::__intern_token::__Matcher::new(input);
+        let mut __states = vec![0_i32];
+        let mut __symbols = vec![];
+        let mut __integer;
+        let mut __lookahead;
+        let mut __last_location = Default::default();
+        '__shift: loop {
+            __lookahead = match __tokens.next() {
+                Some(Ok(v)) => v,
+                None => break '__shift,
+                Some(Err(e)) => return Err(e),
+            };
+            __last_location = __lookahead.2.clone();
+            __integer = match __lookahead.1 {
+                (5, _) if true => 0,
+                (6, _) if true => 1,
+                (7, _) if true => 2,
+                (8, _) if true => 3,
+                (9, _) if true => 4,
+                (10, _) if true => 5,
+                (11, _) if true => 6,
+                (12, _) if true => 7,
+                (13, _) if true => 8,
+                (14, _) if true => 9,
+                (15, _) if true => 10,
+                (16, _) if true => 11,
+                (17, _) if true => 12,
+                (18, _) if true => 13,
+                (19, _) if true => 14,
+                (20, _) if true => 15,
+                (21, _) if true => 16,
+                (22, _) if true => 17,
+                (23, _) if true => 18,
+                (24, _) if true => 19,
+                (25, _) if true => 20,
+                (26, _) if true => 21,
+                (27, _) if true => 22,
+                (28, _) if true => 23,
+                (0, _) if true => 24,
+                (1, _) if true => 25,
+                (2, _) if true => 26,
+                (3, _) if true => 27,
+                (4, _) if true => 28,
+                _ => {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                        token: Some(__lookahead),
+                        expected: __expected_tokens(__state),
+                    };
+                    return Err(__error);
+                }
+            };
+            '__inner: loop {
+                let __state = *__states.last().unwrap() as usize;
+                let __action = __ACTION[__state * 29 + __integer];
+                if __action > 0 {
+                    let __symbol = match __integer {
+                        0 => match __lookahead.1 {
+                            (5, __tok0) => __Symbol::Term_22_21_3d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        1 => match __lookahead.1 {
+                            (6, __tok0) => __Symbol::Term_22_28_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        2 => match __lookahead.1 {
+                            (7, __tok0) => __Symbol::Term_22_29_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        3 => match __lookahead.1 {
+                            (8, __tok0) => __Symbol::Term_22_2a_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        4 => match __lookahead.1 {
+                            (9, __tok0) => __Symbol::Term_22_2b_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        5 => match __lookahead.1 {
+                            (10, __tok0) => __Symbol::Term_22_2d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        6 => match __lookahead.1 {
+                            (11, __tok0) => __Symbol::Term_22_2f_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        7 => match __lookahead.1 {
+                            (12, __tok0) => __Symbol::Term_22_3c_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        8 => match __lookahead.1 {
+                            (13, __tok0) => __Symbol::Term_22_3c_3d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        9 => match __lookahead.1 {
+                            (14, __tok0) => __Symbol::Term_22_3d_3d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        10 => match __lookahead.1 {
+                            (15, __tok0) => __Symbol::Term_22_3e_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        11 => match __lookahead.1 {
+                            (16, __tok0) => __Symbol::Term_22_3e_3d_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        12 => match __lookahead.1 {
+                            (17, __tok0) => __Symbol::Term_22False_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        13 => match __lookahead.1 {
+                            (18, __tok0) => __Symbol::Term_22True_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        14 => match __lookahead.1 {
+                            (19, __tok0) => __Symbol::Term_22and_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        15 => match __lookahead.1 {
+                            (20, __tok0) => __Symbol::Term_22not_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        16 => match __lookahead.1 {
+                            (21, __tok0) => __Symbol::Term_22or_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        17 => match __lookahead.1 {
+                            (22, __tok0) => __Symbol::Term_22_b7_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        18 => match __lookahead.1 {
+                            (23, __tok0) => __Symbol::Term_22_f7_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        19 => match __lookahead.1 {
+                            (24, __tok0) => __Symbol::Term_22_2260_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        20 => match __lookahead.1 {
+                            (25, __tok0) => __Symbol::Term_22_2264_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        21 => match __lookahead.1 {
+                            (26, __tok0) => __Symbol::Term_22_2265_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        22 => match __lookahead.1 {
+                            (27, __tok0) => __Symbol::Term_22_fffc_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        23 => match __lookahead.1 {
+                            (28, __tok0) => __Symbol::Term_22_fffc_fffc_22((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        24 => match __lookahead.1 {
+                            (0, __tok0) => __Symbol::Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        25 => match __lookahead.1 {
+                            (1, __tok0) => __Symbol::Termr_23_22_27_5b_5e_27_5d_27_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        26 => match __lookahead.1 {
+                            (2, __tok0) => __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        27 => match __lookahead.1 {
+                            (3, __tok0) => __Symbol::Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        28 => match __lookahead.1 {
+                            (4, __tok0) => __Symbol::Termr_23_22_203a_5b_5e_2039_5d_2039_22_23((__tok0)),
+                            _ => unreachable!(),
+                        },
+                        _ => unreachable!(),
+                    };
+                    __states.push(__action - 1);
+                    __symbols.push((__lookahead.0, __symbol, __lookahead.2));
+                    continue '__shift;
+                } else if __action < 0 {
+                    if let Some(r) = __reduce(input, __action, Some(&__lookahead.0), &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                        return r;
+                    }
+                } else {
+                    let __state = *__states.last().unwrap() as usize;
+                    let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                        token: Some(__lookahead),
+                        expected: __expected_tokens(__state),
+                    };
+                    return Err(__error)
+                }
+            }
+        }
+        loop {
+            let __state = *__states.last().unwrap() as usize;
+            let __action = __EOF_ACTION[__state];
+            if __action < 0 {
+                if let Some(r) = __reduce(input, __action, None, &mut __states, &mut __symbols, ::std::marker::PhantomData::<()>) {
+                    return r;
+                }
+            } else {
+                let __state = *__states.last().unwrap() as usize;
+                let __error = __lalrpop_util::ParseError::UnrecognizedToken {
+                    token: None,
+                    expected: __expected_tokens(__state),
+                };
+                return Err(__error);
+            }
+        }
+    }
+    pub fn __reduce<
+        'input,
+    >(
+        input: &'input str,
+        __action: i32,
+        __lookahead_start: Option<&usize>,
+        __states: &mut ::std::vec::Vec<i32>,
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>,
+        _: ::std::marker::PhantomData<()>,
+    ) -> Option<Result<Box<Expr>,__lalrpop_util::ParseError<usize, (usize, &'input str), (usize, usize, &'static str)>>>
+    {
+        let __nonterminal = match -__action {
+            1 => {
+                // @L =  => ActionFn(46);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action46::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40L(__nt), __end));
+                0
+            }
+            2 => {
+                // @R =  => ActionFn(45);
+                let __start = __symbols.last().map(|s| s.2.clone()).unwrap_or_default();
+                let __end = __lookahead_start.cloned().unwrap_or_else(|| __start.clone());
+                let __nt = super::__action45::<>(input, &__start, &__end);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 0);
+                __symbols.push((__start, __Symbol::Nt_40R(__nt), __end));
+                1
+            }
+            3 => {
+                // ArithmeticExpr = BinExprTier<SummandOp, Factor> => ActionFn(29);
+                let __sym0 = __pop_NtBinExprTier_3cSummandOp_2c_20Factor_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action29::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtArithmeticExpr(__nt), __end));
+                2
+            }
+            4 => {
+                // BinExprTier<ConOp, Negation> = BinExprTier<ConOp, Negation>, ConOp, Negation => ActionFn(53);
+                let __sym2 = __pop_NtNegation(__symbols);
+                let __sym1 = __pop_NtConOp(__symbols);
+                let __sym0 = __pop_NtBinExprTier_3cConOp_2c_20Negation_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action53::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtBinExprTier_3cConOp_2c_20Negation_3e(__nt), __end));
+                3
+            }
+            5 => {
+                // BinExprTier<ConOp, Negation> = Negation => ActionFn(54);
+                let __sym0 = __pop_NtNegation(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action54::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBinExprTier_3cConOp_2c_20Negation_3e(__nt), __end));
+                3
+            }
+            6 => {
+                // BinExprTier<DisOp, Conjunction> = BinExprTier<DisOp, Conjunction>, DisOp, Conjunction => ActionFn(55);
+                let __sym2 = __pop_NtConjunction(__symbols);
+                let __sym1 = __pop_NtDisOp(__symbols);
+                let __sym0 = __pop_NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action55::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__nt), __end));
+                4
+            }
+            7 => {
+                // BinExprTier<DisOp, Conjunction> = Conjunction => ActionFn(56);
+                let __sym0 = __pop_NtConjunction(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action56::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__nt), __end));
+                4
+            }
+            8 => {
+                // BinExprTier<FactorOp, Term> = BinExprTier<FactorOp, Term>, FactorOp, Term => ActionFn(47);
+                let __sym2 = __pop_NtTerm(__symbols);
+                let __sym1 = __pop_NtFactorOp(__symbols);
+                let __sym0 = __pop_NtBinExprTier_3cFactorOp_2c_20Term_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action47::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtBinExprTier_3cFactorOp_2c_20Term_3e(__nt), __end));
+                5
+            }
+            9 => {
+                // BinExprTier<FactorOp, Term> = Term => ActionFn(48);
+                let __sym0 = __pop_NtTerm(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action48::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBinExprTier_3cFactorOp_2c_20Term_3e(__nt), __end));
+                5
+            }
+            10 => {
+                // BinExprTier<SummandOp, Factor> = BinExprTier<SummandOp, Factor>, SummandOp, Factor => ActionFn(49);
+                let __sym2 = __pop_NtFactor(__symbols);
+                let __sym1 = __pop_NtSummandOp(__symbols);
+                let __sym0 = __pop_NtBinExprTier_3cSummandOp_2c_20Factor_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action49::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtBinExprTier_3cSummandOp_2c_20Factor_3e(__nt), __end));
+                6
+            }
+            11 => {
+                // BinExprTier<SummandOp, Factor> = Factor => ActionFn(50);
+                let __sym0 = __pop_NtFactor(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action50::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBinExprTier_3cSummandOp_2c_20Factor_3e(__nt), __end));
+                6
+            }
+            12 => {
+                // Boolean = "True" => ActionFn(9);
+                let __sym0 = __pop_Term_22True_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action9::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
+                7
+            }
+            13 => {
+                // Boolean = "False" => ActionFn(10);
+                let __sym0 = __pop_Term_22False_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action10::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
+                7
+            }
+            14 => {
+                // Boolean = "(", BooleanExpr, ")" => ActionFn(11);
+                let __sym2 = __pop_Term_22_29_22(__symbols);
+                let __sym1 = __pop_NtBooleanExpr(__symbols);
+                let __sym0 = __pop_Term_22_28_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action11::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
+                7
+            }
+            15 => {
+                // Boolean = Comparison => ActionFn(12);
+                let __sym0 = __pop_NtComparison(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action12::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
+                7
+            }
+            16 => {
+                // Boolean = "￼￼" => ActionFn(13);
+                let __sym0 = __pop_Term_22_fffc_fffc_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action13::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBoolean(__nt), __end));
+                7
+            }
+            17 => {
+                // BooleanExpr = BinExprTier<DisOp, Conjunction> => ActionFn(6);
+                let __sym0 = __pop_NtBinExprTier_3cDisOp_2c_20Conjunction_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action6::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtBooleanExpr(__nt), __end));
+                8
+            }
+            18 => {
+                // Char = r#"'[^']'"# => ActionFn(43);
+                let __sym0 = __pop_Termr_23_22_27_5b_5e_27_5d_27_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action43::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtChar(__nt), __end));
+                9
+            }
+            19 => {
+                // Char = r#"›[^‹]‹"# => ActionFn(44);
+                let __sym0 = __pop_Termr_23_22_203a_5b_5e_2039_5d_2039_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action44::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtChar(__nt), __end));
+                9
+            }
+            20 => {
+                // CompOp = "==" => ActionFn(20);
+                let __sym0 = __pop_Term_22_3d_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action20::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
+                10
+            }
+            21 => {
+                // CompOp = "!=" => ActionFn(21);
+                let __sym0 = __pop_Term_22_21_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action21::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
+                10
+            }
+            22 => {
+                // CompOp = "≠" => ActionFn(22);
+                let __sym0 = __pop_Term_22_2260_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action22::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
+                10
+            }
+            23 => {
+                // CompOp = "<=" => ActionFn(23);
+                let __sym0 = __pop_Term_22_3c_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action23::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
+                10
+            }
+            24 => {
+                // CompOp = "≤" => ActionFn(24);
+                let __sym0 = __pop_Term_22_2264_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action24::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
+                10
+            }
+            25 => {
+                // CompOp = ">=" => ActionFn(25);
+                let __sym0 = __pop_Term_22_3e_3d_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action25::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
+                10
+            }
+            26 => {
+                // CompOp = "≥" => ActionFn(26);
+                let __sym0 = __pop_Term_22_2265_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action26::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
+                10
+            }
+            27 => {
+                // CompOp = "<" => ActionFn(27);
+                let __sym0 = __pop_Term_22_3c_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action27::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
+                10
+            }
+            28 => {
+                // CompOp = ">" => ActionFn(28);
+                let __sym0 = __pop_Term_22_3e_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action28::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtCompOp(__nt), __end));
+                10
+            }
+            29 => {
+                // Comparison = ArithmeticExpr, CompOp, ArithmeticExpr => ActionFn(17);
+                let __sym2 = __pop_NtArithmeticExpr(__symbols);
+                let __sym1 = __pop_NtCompOp(__symbols);
+                let __sym0 = __pop_NtArithmeticExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym2.2.clone();
+                let __nt = super::__action17::<>(input, __sym0, __sym1, __sym2);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 3);
+                __symbols.push((__start, __Symbol::NtComparison(__nt), __end));
+                11
+            }
+            30 => {
+                // Comparison = Str => ActionFn(18);
+                let __sym0 = __pop_NtStr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action18::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtComparison(__nt), __end));
+                11
+            }
+            31 => {
+                // Comparison = Char => ActionFn(19);
+                let __sym0 = __pop_NtChar(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action19::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtComparison(__nt), __end));
+                11
+            }
+            32 => {
+                // ConOp = "and" => ActionFn(15);
+                let __sym0 = __pop_Term_22and_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action15::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtConOp(__nt), __end));
+                12
+            }
+            33 => {
+                // Conjunction = BinExprTier<ConOp, Negation> => ActionFn(7);
+                let __sym0 = __pop_NtBinExprTier_3cConOp_2c_20Negation_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action7::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtConjunction(__nt), __end));
+                13
+            }
+            34 => {
+                // DisOp = "or" => ActionFn(14);
+                let __sym0 = __pop_Term_22or_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action14::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtDisOp(__nt), __end));
+                14
+            }
+            35 => {
+                // Expr = BooleanExpr => ActionFn(4);
+                let __sym0 = __pop_NtBooleanExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action4::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                15
+            }
+            36 => {
+                // Expr = ArithmeticExpr => ActionFn(5);
+                let __sym0 = __pop_NtArithmeticExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action5::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtExpr(__nt), __end));
+                15
+            }
+            37 => {
+                // Factor = BinExprTier<FactorOp, Term> => ActionFn(30);
+                let __sym0 = __pop_NtBinExprTier_3cFactorOp_2c_20Term_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action30::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtFactor(__nt), __end));
+                16
+            }
+            38 => {
+                // FactorOp = "*" => ActionFn(37);
+                let __sym0 = __pop_Term_22_2a_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action37::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                17
             }
             39 => {
-                // SummandOp = "-" => ActionFn(29);
+                // FactorOp = "·" => ActionFn(38);
+                let __sym0 = __pop_Term_22_b7_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action38::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                17
+            }
+            40 => {
+                // FactorOp = "/" => ActionFn(39);
+                let __sym0 = __pop_Term_22_2f_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action39::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                17
+            }
+            41 => {
+                // FactorOp = "÷" => ActionFn(40);
+                let __sym0 = __pop_Term_22_f7_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action40::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtFactorOp(__nt), __end));
+                17
+            }
+            42 => {
+                // NegOp = "not" => ActionFn(16);
+                let __sym0 = __pop_Term_22not_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action16::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNegOp(__nt), __end));
+                18
+            }
+            43 => {
+                // Negation = UnExprTier<NegOp, Boolean> => ActionFn(8);
+                let __sym0 = __pop_NtUnExprTier_3cNegOp_2c_20Boolean_3e(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action8::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNegation(__nt), __end));
+                19
+            }
+            44 => {
+                // Num = r#"[0-9]+"# => ActionFn(58);
+                let __sym0 = __pop_Termr_23_22_5b0_2d9_5d_2b_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = match super::__action58::<>(input, __sym0) {
+                    Ok(v) => v,
+                    Err(e) => return Some(Err(e)),
+                };
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtNum(__nt), __end));
+                20
+            }
+            45 => {
+                // Str = r#"\"[^\"]*\""# => ActionFn(41);
+                let __sym0 = __pop_Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action41::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
+                21
+            }
+            46 => {
+                // Str = r#"»[^«]*«"# => ActionFn(42);
+                let __sym0 = __pop_Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action42::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtStr(__nt), __end));
+                21
+            }
+            47 => {
+                // SummandOp = "+" => ActionFn(35);
+                let __sym0 = __pop_Term_22_2b_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action35::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtSummandOp(__nt), __end));
+                22
+            }
+            48 => {
+                // SummandOp = "-" => ActionFn(36);
                 let __sym0 = __pop_Term_22_2d_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action29::<>(input, __sym0);
+                let __nt = super::__action36::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtSummandOp(__nt), __end));
-                19
+                22
             }
-            40 => {
-                // Term = Num => ActionFn(25);
+            49 => {
+                // Term = Num => ActionFn(31);
                 let __sym0 = __pop_NtNum(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action25::<>(input, __sym0);
+                let __nt = super::__action31::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
-                20
+                23
             }
-            41 => {
-                // Term = "(", ArithmeticExpr, ")" => ActionFn(26);
+            50 => {
+                // Term = "(", ArithmeticExpr, ")" => ActionFn(32);
                 let __sym2 = __pop_Term_22_29_22(__symbols);
                 let __sym1 = __pop_NtArithmeticExpr(__symbols);
                 let __sym0 = __pop_Term_22_28_22(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym2.2.clone();
-                let __nt = super::__action26::<>(input, __sym0, __sym1, __sym2);
+                let __nt = super::__action32::<>(input, __sym0, __sym1, __sym2);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 3);
                 __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
-                20
+                23
             }
-            42 => {
-                // UnExprTier<NegOp, Boolean> = NegOp, Boolean => ActionFn(40);
+            51 => {
+                // Term = "￼" => ActionFn(33);
+                let __sym0 = __pop_Term_22_fffc_22(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action33::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::NtTerm(__nt), __end));
+                23
+            }
+            52 => {
+                // UnExprTier<NegOp, Boolean> = NegOp, Boolean => ActionFn(51);
                 let __sym1 = __pop_NtBoolean(__symbols);
                 let __sym0 = __pop_NtNegOp(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym1.2.clone();
-                let __nt = super::__action40::<>(input, __sym0, __sym1);
+                let __nt = super::__action51::<>(input, __sym0, __sym1);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 2);
                 __symbols.push((__start, __Symbol::NtUnExprTier_3cNegOp_2c_20Boolean_3e(__nt), __end));
-                21
+                24
             }
-            43 => {
-                // UnExprTier<NegOp, Boolean> = Boolean => ActionFn(41);
+            53 => {
+                // UnExprTier<NegOp, Boolean> = Boolean => ActionFn(52);
                 let __sym0 = __pop_NtBoolean(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action41::<>(input, __sym0);
+                let __nt = super::__action52::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::NtUnExprTier_3cNegOp_2c_20Boolean_3e(__nt), __end));
-                21
+                24
             }
-            44 => {
-                // __ArithmeticExpr = ArithmeticExpr => ActionFn(2);
+            54 => {
+                // __ArithmeticExpr = ArithmeticExpr => ActionFn(3);
                 let __sym0 = __pop_NtArithmeticExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action2::<>(input, __sym0);
+                let __nt = super::__action3::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::Nt____ArithmeticExpr(__nt), __end));
-                22
+                25
             }
-            45 => {
-                // __BooleanExpr = BooleanExpr => ActionFn(0);
+            55 => {
+                // __BooleanExpr = BooleanExpr => ActionFn(1);
                 let __sym0 = __pop_NtBooleanExpr(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action0::<>(input, __sym0);
+                let __nt = super::__action1::<>(input, __sym0);
                 let __states_len = __states.len();
                 __states.truncate(__states_len - 1);
                 __symbols.push((__start, __Symbol::Nt____BooleanExpr(__nt), __end));
-                23
+                26
             }
-            46 => {
-                // __Comparison = Comparison => ActionFn(1);
+            56 => {
+                // __Comparison = Comparison => ActionFn(2);
                 let __sym0 = __pop_NtComparison(__symbols);
                 let __start = __sym0.0.clone();
                 let __end = __sym0.2.clone();
-                let __nt = super::__action1::<>(input, __sym0);
+                let __nt = super::__action2::<>(input, __sym0);
+                let __states_len = __states.len();
+                __states.truncate(__states_len - 1);
+                __symbols.push((__start, __Symbol::Nt____Comparison(__nt), __end));
+                27
+            }
+            57 => {
+                // __Expr = Expr => ActionFn(0);
+                let __sym0 = __pop_NtExpr(__symbols);
+                let __start = __sym0.0.clone();
+                let __end = __sym0.2.clone();
+                let __nt = super::__action0::<>(input, __sym0);
                 return Some(Ok(__nt));
             }
             _ => panic!("invalid action code {}", __action)
         };
         let __state = *__states.last().unwrap() as usize;
-        let __next_state = __GOTO[__state * 25 + __nonterminal] - 1;
+        let __next_state = __GOTO[__state * 29 + __nonterminal] - 1;
         __states.push(__next_state);
         None
     }
@@ -4032,16 +6664,6 @@ mod __parse__Comparison {
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_5c_27_2e_5c_27_22<
-      'input,
-    >(
-        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
-        match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_5c_27_2e_5c_27_22(__v), __r) => (__l, __v, __r),
-            _ => panic!("symbol type mismatch")
-        }
-    }
     fn __pop_Term_22_28_22<
       'input,
     >(
@@ -4092,183 +6714,253 @@ mod __parse__Comparison {
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_2f_22<
+    fn __pop_Term_22_2f_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3c_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3c_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3c_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3c_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3d_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3d_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3e_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3e_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22_3e_3d_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22_3e_3d_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22False_22<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, &'input str, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Term_22False_22(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
+    fn __pop_Term_22True_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2f_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22True_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_3c_22<
+    fn __pop_Term_22and_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3c_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22and_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_3c_3d_22<
+    fn __pop_Term_22not_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3c_3d_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22not_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_3d_3d_22<
+    fn __pop_Term_22or_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3d_3d_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22or_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_3e_22<
+    fn __pop_Term_22_b7_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3e_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22_b7_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_3e_3d_22<
+    fn __pop_Term_22_f7_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_3e_3d_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22_f7_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22False_22<
+    fn __pop_Term_22_2260_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22False_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22_2260_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22True_22<
+    fn __pop_Term_22_2264_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22True_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22_2264_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22<
+    fn __pop_Term_22_2265_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_5c_5c_5c_22_2e_2a_5c_5c_5c_22_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22_2265_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22and_22<
+    fn __pop_Term_22_fffc_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22and_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22_fffc_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22not_22<
+    fn __pop_Term_22_fffc_fffc_22<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22not_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Term_22_fffc_fffc_22(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22or_22<
+    fn __pop_Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22or_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Termr_23_22_5c_22_5b_5e_5c_22_5d_2a_5c_22_22_23(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_bb_2e_2a_ab_22<
+    fn __pop_Termr_23_22_27_5b_5e_27_5d_27_22_23<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_bb_2e_2a_ab_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Termr_23_22_27_5b_5e_27_5d_27_22_23(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_203a_2e_2039_22<
+    fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_203a_2e_2039_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_2260_22<
+    fn __pop_Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2260_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Termr_23_22_bb_5b_5e_ab_5d_2a_ab_22_23(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_2264_22<
+    fn __pop_Termr_23_22_203a_5b_5e_2039_5d_2039_22_23<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
     ) -> (usize, &'input str, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2264_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Termr_23_22_203a_5b_5e_2039_5d_2039_22_23(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Term_22_2265_22<
+    fn __pop_Nt_40L<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, usize, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Term_22_2265_22(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Nt_40L(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
-    fn __pop_Termr_23_22_5b0_2d9_5d_2b_22_23<
+    fn __pop_Nt_40R<
       'input,
     >(
         __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
-    ) -> (usize, &'input str, usize) {
+    ) -> (usize, usize, usize) {
         match __symbols.pop().unwrap() {
-            (__l, __Symbol::Termr_23_22_5b0_2d9_5d_2b_22_23(__v), __r) => (__l, __v, __r),
+            (__l, __Symbol::Nt_40R(__v), __r) => (__l, __v, __r),
             _ => panic!("symbol type mismatch")
         }
     }
@@ -4402,6 +7094,16 @@ mod __parse__Comparison {
             _ => panic!("symbol type mismatch")
         }
     }
+    fn __pop_NtExpr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::NtExpr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
     fn __pop_NtFactor<
       'input,
     >(
@@ -4522,12 +7224,23 @@ mod __parse__Comparison {
             _ => panic!("symbol type mismatch")
         }
     }
+    fn __pop_Nt____Expr<
+      'input,
+    >(
+        __symbols: &mut ::std::vec::Vec<(usize,__Symbol<'input>,usize)>
+    ) -> (usize, Box<Expr>, usize) {
+        match __symbols.pop().unwrap() {
+            (__l, __Symbol::Nt____Expr(__v), __r) => (__l, __v, __r),
+            _ => panic!("symbol type mismatch")
+        }
+    }
 }
-pub use self::__parse__Comparison::parse_Comparison;
+pub use self::__parse__Expr::parse_Expr;
 mod __intern_token {
     #![allow(unused_imports)]
     use std::str::FromStr;
     use ast::{Expr, Opcode};
+    use lalrpop_util::ParseError;
     extern crate lalrpop_util as __lalrpop_util;
     extern crate regex as __regex;
     pub struct __Matcher<'input> {
@@ -4540,9 +7253,12 @@ mod __intern_token {
     impl<'input> __Matcher<'input> {
         pub fn new(s: &'input str) -> __Matcher<'input> {
             let __strs: &[&str] = &[
+                "^(?u:\")(?u:[\0-!\\#-\u{10ffff}])*(?u:\")",
+                "^(?u:')(?u:[\0-\\&\\(-\u{10ffff}])(?u:')",
                 "^(?u:[0-9])+",
+                "^(?u:»)(?u:[\0-ª¬-\u{10ffff}])*(?u:«)",
+                "^(?u:›)(?u:[\0-‸›-\u{10ffff}])(?u:‹)",
                 "^(?u:!=)",
-                "^(?u:\'\\.\')",
                 "^(?u:\\()",
                 "^(?u:\\))",
                 "^(?u:\\*)",
@@ -4556,21 +7272,25 @@ mod __intern_token {
                 "^(?u:>=)",
                 "^(?u:False)",
                 "^(?u:True)",
-                "^(?u:\\\\\"\\.\\*\\\\\")",
                 "^(?u:and)",
                 "^(?u:not)",
                 "^(?u:or)",
-                "^(?u:»\\.\\*«)",
-                "^(?u:›\\.‹)",
+                "^(?u:·)",
+                "^(?u:÷)",
                 "^(?u:≠)",
                 "^(?u:≤)",
                 "^(?u:≥)",
+                "^(?u:￼)",
+                "^(?u:￼￼)",
             ];
             let __regex_set = __regex::RegexSet::new(__strs).unwrap();
             let __regex_vec = vec![
+                __regex::Regex::new("^(?u:\")(?u:[\0-!\\#-\u{10ffff}])*(?u:\")").unwrap(),
+                __regex::Regex::new("^(?u:')(?u:[\0-\\&\\(-\u{10ffff}])(?u:')").unwrap(),
                 __regex::Regex::new("^(?u:[0-9])+").unwrap(),
+                __regex::Regex::new("^(?u:»)(?u:[\0-ª¬-\u{10ffff}])*(?u:«)").unwrap(),
+                __regex::Regex::new("^(?u:›)(?u:[\0-‸›-\u{10ffff}])(?u:‹)").unwrap(),
                 __regex::Regex::new("^(?u:!=)").unwrap(),
-                __regex::Regex::new("^(?u:\'\\.\')").unwrap(),
                 __regex::Regex::new("^(?u:\\()").unwrap(),
                 __regex::Regex::new("^(?u:\\))").unwrap(),
                 __regex::Regex::new("^(?u:\\*)").unwrap(),
@@ -4584,15 +7304,16 @@ mod __intern_token {
                 __regex::Regex::new("^(?u:>=)").unwrap(),
                 __regex::Regex::new("^(?u:False)").unwrap(),
                 __regex::Regex::new("^(?u:True)").unwrap(),
-                __regex::Regex::new("^(?u:\\\\\"\\.\\*\\\\\")").unwrap(),
                 __regex::Regex::new("^(?u:and)").unwrap(),
                 __regex::Regex::new("^(?u:not)").unwrap(),
                 __regex::Regex::new("^(?u:or)").unwrap(),
-                __regex::Regex::new("^(?u:»\\.\\*«)").unwrap(),
-                __regex::Regex::new("^(?u:›\\.‹)").unwrap(),
+                __regex::Regex::new("^(?u:·)").unwrap(),
+                __regex::Regex::new("^(?u:÷)").unwrap(),
                 __regex::Regex::new("^(?u:≠)").unwrap(),
                 __regex::Regex::new("^(?u:≤)").unwrap(),
                 __regex::Regex::new("^(?u:≥)").unwrap(),
+                __regex::Regex::new("^(?u:￼)").unwrap(),
+                __regex::Regex::new("^(?u:￼￼)").unwrap(),
             ];
             __Matcher {
                 text: s,
@@ -4604,7 +7325,7 @@ mod __intern_token {
     }
 
     impl<'input> Iterator for __Matcher<'input> {
-        type Item = Result<(usize, (usize, &'input str), usize), __lalrpop_util::ParseError<usize,(usize, &'input str),()>>;
+        type Item = Result<(usize, (usize, &'input str), usize), __lalrpop_util::ParseError<usize,(usize, &'input str),(usize, usize, &'static str)>>;
 
         fn next(&mut self) -> Option<Self::Item> {
             let __text = self.text.trim_left();
@@ -4623,7 +7344,7 @@ mod __intern_token {
                 } else {
                     let mut __longest_match = 0;
                     let mut __index = 0;
-                    for __i in 0 .. 25 {
+                    for __i in 0 .. 29 {
                         if __matches.matched(__i) {
                             let __match = self.regex_vec[__i].find(__text).unwrap();
                             let __len = __match.end();
@@ -4714,6 +7435,39 @@ fn __action5<
 #[allow(unused_variables)]
 fn __action6<
     'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action7<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action8<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, Box<Expr>, usize),
+) -> Box<Expr>
+{
+    (__0)
+}
+
+#[allow(unused_variables)]
+fn __action9<
+    'input,
 >(
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
@@ -4723,7 +7477,7 @@ fn __action6<
 }
 
 #[allow(unused_variables)]
-fn __action7<
+fn __action10<
     'input,
 >(
     input: &'input str,
@@ -4734,7 +7488,7 @@ fn __action7<
 }
 
 #[allow(unused_variables)]
-fn __action8<
+fn __action11<
     'input,
 >(
     input: &'input str,
@@ -4747,7 +7501,7 @@ fn __action8<
 }
 
 #[allow(unused_variables)]
-fn __action9<
+fn __action12<
     'input,
 >(
     input: &'input str,
@@ -4758,7 +7512,18 @@ fn __action9<
 }
 
 #[allow(unused_variables)]
-fn __action10<
+fn __action13<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Error)
+}
+
+#[allow(unused_variables)]
+fn __action14<
     'input,
 >(
     input: &'input str,
@@ -4769,7 +7534,7 @@ fn __action10<
 }
 
 #[allow(unused_variables)]
-fn __action11<
+fn __action15<
     'input,
 >(
     input: &'input str,
@@ -4780,7 +7545,7 @@ fn __action11<
 }
 
 #[allow(unused_variables)]
-fn __action12<
+fn __action16<
     'input,
 >(
     input: &'input str,
@@ -4791,7 +7556,7 @@ fn __action12<
 }
 
 #[allow(unused_variables)]
-fn __action13<
+fn __action17<
     'input,
 >(
     input: &'input str,
@@ -4804,7 +7569,29 @@ fn __action13<
 }
 
 #[allow(unused_variables)]
-fn __action14<
+fn __action18<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, String, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Str(__0))
+}
+
+#[allow(unused_variables)]
+fn __action19<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, char, usize),
+) -> Box<Expr>
+{
+    Box::new(Expr::Char(__0))
+}
+
+#[allow(unused_variables)]
+fn __action20<
     'input,
 >(
     input: &'input str,
@@ -4815,7 +7602,7 @@ fn __action14<
 }
 
 #[allow(unused_variables)]
-fn __action15<
+fn __action21<
     'input,
 >(
     input: &'input str,
@@ -4826,7 +7613,7 @@ fn __action15<
 }
 
 #[allow(unused_variables)]
-fn __action16<
+fn __action22<
     'input,
 >(
     input: &'input str,
@@ -4837,7 +7624,7 @@ fn __action16<
 }
 
 #[allow(unused_variables)]
-fn __action17<
+fn __action23<
     'input,
 >(
     input: &'input str,
@@ -4848,7 +7635,7 @@ fn __action17<
 }
 
 #[allow(unused_variables)]
-fn __action18<
+fn __action24<
     'input,
 >(
     input: &'input str,
@@ -4859,7 +7646,7 @@ fn __action18<
 }
 
 #[allow(unused_variables)]
-fn __action19<
+fn __action25<
     'input,
 >(
     input: &'input str,
@@ -4870,7 +7657,7 @@ fn __action19<
 }
 
 #[allow(unused_variables)]
-fn __action20<
+fn __action26<
     'input,
 >(
     input: &'input str,
@@ -4881,7 +7668,7 @@ fn __action20<
 }
 
 #[allow(unused_variables)]
-fn __action21<
+fn __action27<
     'input,
 >(
     input: &'input str,
@@ -4892,7 +7679,7 @@ fn __action21<
 }
 
 #[allow(unused_variables)]
-fn __action22<
+fn __action28<
     'input,
 >(
     input: &'input str,
@@ -4903,7 +7690,7 @@ fn __action22<
 }
 
 #[allow(unused_variables)]
-fn __action23<
+fn __action29<
     'input,
 >(
     input: &'input str,
@@ -4914,7 +7701,7 @@ fn __action23<
 }
 
 #[allow(unused_variables)]
-fn __action24<
+fn __action30<
     'input,
 >(
     input: &'input str,
@@ -4925,7 +7712,7 @@ fn __action24<
 }
 
 #[allow(unused_variables)]
-fn __action25<
+fn __action31<
     'input,
 >(
     input: &'input str,
@@ -4936,7 +7723,7 @@ fn __action25<
 }
 
 #[allow(unused_variables)]
-fn __action26<
+fn __action32<
     'input,
 >(
     input: &'input str,
@@ -4949,18 +7736,32 @@ fn __action26<
 }
 
 #[allow(unused_variables)]
-fn __action27<
+fn __action33<
     'input,
 >(
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
-) -> i32
+) -> Box<Expr>
 {
-    i32::from_str(__0).unwrap()
+    Box::new(Expr::Error)
 }
 
 #[allow(unused_variables)]
-fn __action28<
+fn __action34<
+    'input,
+>(
+    input: &'input str,
+    (_, start, _): (usize, usize, usize),
+    (_, digits, _): (usize, &'input str, usize),
+    (_, end, _): (usize, usize, usize),
+) -> Result<i32,__lalrpop_util::ParseError<usize,(usize, &'input str),(usize, usize, &'static str)>>
+{
+    i32::from_str(digits)
+		.map_err(|_| ParseError::User { error: (start, end, "number too large") })
+}
+
+#[allow(unused_variables)]
+fn __action35<
     'input,
 >(
     input: &'input str,
@@ -4971,7 +7772,7 @@ fn __action28<
 }
 
 #[allow(unused_variables)]
-fn __action29<
+fn __action36<
     'input,
 >(
     input: &'input str,
@@ -4982,7 +7783,7 @@ fn __action29<
 }
 
 #[allow(unused_variables)]
-fn __action30<
+fn __action37<
     'input,
 >(
     input: &'input str,
@@ -4993,7 +7794,18 @@ fn __action30<
 }
 
 #[allow(unused_variables)]
-fn __action31<
+fn __action38<
+    'input,
+>(
+    input: &'input str,
+    (_, __0, _): (usize, &'input str, usize),
+) -> Opcode
+{
+    Opcode::Mul
+}
+
+#[allow(unused_variables)]
+fn __action39<
     'input,
 >(
     input: &'input str,
@@ -5004,51 +7816,86 @@ fn __action31<
 }
 
 #[allow(unused_variables)]
-fn __action32<
+fn __action40<
     'input,
 >(
     input: &'input str,
     (_, __0, _): (usize, &'input str, usize),
+) -> Opcode
+{
+    Opcode::Div
+}
+
+#[allow(unused_variables)]
+fn __action41<
+    'input,
+>(
+    input: &'input str,
+    (_, s, _): (usize, &'input str, usize),
 ) -> String
 {
-    String::from_str(__0).unwrap()
+    s[1..s.len() - 1].to_string()
 }
 
 #[allow(unused_variables)]
-fn __action33<
+fn __action42<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
+    (_, s, _): (usize, &'input str, usize),
 ) -> String
 {
-    String::from_str(__0).unwrap()
+    s[2..s.len() - 2].to_string()
 }
 
 #[allow(unused_variables)]
-fn __action34<
+fn __action43<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
+    (_, s, _): (usize, &'input str, usize),
 ) -> char
 {
-    char::from_str(__0).unwrap()
+    s[1..].chars().next().unwrap()
 }
 
 #[allow(unused_variables)]
-fn __action35<
+fn __action44<
     'input,
 >(
     input: &'input str,
-    (_, __0, _): (usize, &'input str, usize),
+    (_, s, _): (usize, &'input str, usize),
 ) -> char
 {
-    char::from_str(__0).unwrap()
+    s[3..].chars().next().unwrap()
 }
 
 #[allow(unused_variables)]
-fn __action36<
+fn __action45<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookbehind.clone()
+}
+
+#[allow(unused_variables)]
+fn __action46<
+    'input,
+>(
+    input: &'input str,
+    __lookbehind: &usize,
+    __lookahead: &usize,
+) -> usize
+{
+    __lookahead.clone()
+}
+
+#[allow(unused_variables)]
+fn __action47<
     'input,
 >(
     input: &'input str,
@@ -5061,7 +7908,7 @@ fn __action36<
 }
 
 #[allow(unused_variables)]
-fn __action37<
+fn __action48<
     'input,
 >(
     input: &'input str,
@@ -5072,7 +7919,7 @@ fn __action37<
 }
 
 #[allow(unused_variables)]
-fn __action38<
+fn __action49<
     'input,
 >(
     input: &'input str,
@@ -5085,7 +7932,7 @@ fn __action38<
 }
 
 #[allow(unused_variables)]
-fn __action39<
+fn __action50<
     'input,
 >(
     input: &'input str,
@@ -5096,7 +7943,7 @@ fn __action39<
 }
 
 #[allow(unused_variables)]
-fn __action40<
+fn __action51<
     'input,
 >(
     input: &'input str,
@@ -5108,7 +7955,7 @@ fn __action40<
 }
 
 #[allow(unused_variables)]
-fn __action41<
+fn __action52<
     'input,
 >(
     input: &'input str,
@@ -5119,7 +7966,7 @@ fn __action41<
 }
 
 #[allow(unused_variables)]
-fn __action42<
+fn __action53<
     'input,
 >(
     input: &'input str,
@@ -5132,7 +7979,7 @@ fn __action42<
 }
 
 #[allow(unused_variables)]
-fn __action43<
+fn __action54<
     'input,
 >(
     input: &'input str,
@@ -5143,7 +7990,7 @@ fn __action43<
 }
 
 #[allow(unused_variables)]
-fn __action44<
+fn __action55<
     'input,
 >(
     input: &'input str,
@@ -5156,7 +8003,7 @@ fn __action44<
 }
 
 #[allow(unused_variables)]
-fn __action45<
+fn __action56<
     'input,
 >(
     input: &'input str,
@@ -5166,20 +8013,68 @@ fn __action45<
     (__0)
 }
 
+#[allow(unused_variables)]
+fn __action57<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+    __1: (usize, usize, usize),
+) -> Result<i32,__lalrpop_util::ParseError<usize,(usize, &'input str),(usize, usize, &'static str)>>
+{
+    let __start0 = __0.0.clone();
+    let __end0 = __0.0.clone();
+    let __temp0 = __action46(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action34(
+        input,
+        __temp0,
+        __0,
+        __1,
+    )
+}
+
+#[allow(unused_variables)]
+fn __action58<
+    'input,
+>(
+    input: &'input str,
+    __0: (usize, &'input str, usize),
+) -> Result<i32,__lalrpop_util::ParseError<usize,(usize, &'input str),(usize, usize, &'static str)>>
+{
+    let __start0 = __0.2.clone();
+    let __end0 = __0.2.clone();
+    let __temp0 = __action45(
+        input,
+        &__start0,
+        &__end0,
+    );
+    let __temp0 = (__start0, __temp0, __end0);
+    __action57(
+        input,
+        __0,
+        __temp0,
+    )
+}
+
 pub trait __ToTriple<'input, > {
     type Error;
     fn to_triple(value: Self) -> Result<(usize,(usize, &'input str),usize),Self::Error>;
 }
 
 impl<'input, > __ToTriple<'input, > for (usize, (usize, &'input str), usize) {
-    type Error = ();
-    fn to_triple(value: Self) -> Result<(usize,(usize, &'input str),usize),()> {
+    type Error = (usize, usize, &'static str);
+    fn to_triple(value: Self) -> Result<(usize,(usize, &'input str),usize),(usize, usize, &'static str)> {
         Ok(value)
     }
 }
-impl<'input, > __ToTriple<'input, > for Result<(usize, (usize, &'input str), usize),()> {
-    type Error = ();
-    fn to_triple(value: Self) -> Result<(usize,(usize, &'input str),usize),()> {
+impl<'input, > __ToTriple<'input, > for Result<(usize, (usize, &'input str), usize),(usize, usize, &'static str)> {
+    type Error = (usize, usize, &'static str);
+    fn to_triple(value: Self) -> Result<(usize,(usize, &'input str),usize),(usize, usize, &'static str)> {
         value
     }
 }
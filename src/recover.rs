@@ -0,0 +1,318 @@
+use std::fmt::{self, Display, Formatter};
+
+use lalrpop_util::ParseError;
+use ast::{Expr, MAX_DEPTH};
+use parser;
+
+/// Stands for an arithmetic expression that didn't parse.
+const NUMBER_HOLE: &str = "￼";
+/// Stands for a boolean expression that didn't parse.
+const BOOL_HOLE: &str = "￼￼";
+
+/// The errors of the generated parser. Its own errors carry the byte range
+/// of a number that doesn't fit.
+type Error<'input> = ParseError<usize, (usize, &'input str), (usize, usize, &'static str)>;
+
+/// A syntax error found by `parse`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct SyntaxError {
+	/// The byte range of the offending source, empty if something is missing.
+	pub start: usize,
+	pub end: usize,
+	/// The position of `start`, counting lines and characters from 1.
+	pub line: usize,
+	pub column: usize,
+	pub message: String,
+}
+
+impl Display for SyntaxError {
+	fn fmt(&self, fmt: &mut Formatter) -> fmt::Result {
+		write!(fmt, "{}:{}: {}", self.line, self.column, self.message)
+	}
+}
+
+/// Parses `source`, continuing past syntax errors, for tools like the
+/// language server and the formatter that should report every error in a
+/// file rather than the first. This is the public way to parse Moses; the
+/// generated parser stays private, since it accepts the placeholders.
+///
+/// After each error, the source is repaired by inserting a placeholder for a
+/// missing expression, inserting a missing `)` or dropping the offending
+/// token, whichever lets the parser get further. Placeholders become
+/// `Expr::Error` nodes. If no repair helps, or the expression is nested
+/// deeper than `ast::MAX_DEPTH`, the whole tree is `Expr::Error`.
+///
+/// `#` comments are blanked out before parsing, since the lexer LALRPOP 0.13
+/// generates can't skip them.
+///
+/// LALRPOP's own error recovery (`!`) isn't used, because the parsers
+/// generated by version 0.13 loop forever on inputs like `1 2`. They also
+/// stop at a `)` without a matching `(` and accept what came before, so those
+/// are found here.
+pub fn parse(source: &str) -> (Box<Expr>, Vec<SyntaxError>) {
+	let mut text = blank_comments(source);
+	let mut shifts = Shifts(Vec::new());
+	// Placeholders in the source itself are errors too, even though they
+	// parse. Each run of them is replaced by the hole that fits there, so the
+	// parser doesn't report them again.
+	let mut errors = Vec::new();
+	let mut from = 0;
+	while let Some(found) = text[from..].find(NUMBER_HOLE) {
+		let start = from + found;
+		let end = start + text[start..].chars().take_while(|&c| c == '\u{FFFC}').map(char::len_utf8).sum::<usize>();
+		for (offset, hole) in text[start..end].char_indices() {
+			let start = shifts.original(start + offset);
+			let (line, column) = position(source, start);
+			let message = format!("unexpected character `{}`", hole);
+			errors.push(SyntaxError { start, end: start + hole.len_utf8(), line, column, message });
+		}
+		// Dropping the run always helps, so one of them does.
+		let (repair, repaired) = [NUMBER_HOLE, BOOL_HOLE, ""].iter().find_map(|&insert| {
+			let repair = Repair { start, end, insert };
+			let mut repaired = text.clone();
+			repaired.replace_range(start..end, insert);
+			if repair.helps(&repaired) { Some((repair, repaired)) } else { None }
+		}).unwrap();
+		from = start + repair.insert.len();
+		shifts.record(&repair);
+		text = repaired;
+	}
+	loop {
+		let parsed = parser::parse_Expr(&text);
+		let stop = parsed.as_ref().err().map(|err| location(err, &text));
+		let (start, end, message, repairs) = match (unmatched_paren(&text), parsed) {
+			(Some(at), _) if stop.is_none_or(|stop| at <= stop) => {
+				let repairs = vec![
+					Repair { start: at, end: at + 1, insert: NUMBER_HOLE },
+					Repair { start: at, end: at + 1, insert: BOOL_HOLE },
+					Repair { start: at, end: at + 1, insert: "" },
+				];
+				(at, at + 1, "unmatched `)`".to_string(), repairs)
+			}
+			(_, Ok(ref expr)) if expr.depth() > MAX_DEPTH => {
+				// Reported for the whole source, the tree doesn't know where
+				// it's nested deepest.
+				let message = format!("expressions nested deeper than {}", MAX_DEPTH);
+				errors.push(SyntaxError { start: 0, end: source.len(), line: 1, column: 1, message });
+				errors.sort_by_key(|err| err.start);
+				return (Box::new(Expr::Error), errors);
+			}
+			(_, Ok(expr)) => {
+				errors.sort_by_key(|err| err.start);
+				return (expr, errors);
+			}
+			(_, Err(err)) => diagnose(err, &text),
+		};
+		let (start, end) = (shifts.original(start), shifts.original(end));
+		let (line, column) = position(source, start);
+		errors.push(SyntaxError { start, end, line, column, message });
+		let repaired = repairs.into_iter().find_map(|repair| {
+			let mut repaired = text.clone();
+			repaired.replace_range(repair.start..repair.end, repair.insert);
+			if repair.helps(&repaired) { Some((repair, repaired)) } else { None }
+		});
+		match repaired {
+			Some((repair, repaired)) => {
+				shifts.record(&repair);
+				text = repaired;
+			}
+			None => {
+				errors.sort_by_key(|err| err.start);
+				return (Box::new(Expr::Error), errors);
+			}
+		}
+	}
+}
+
+/// Replaces `start..end` of the source with `insert`.
+struct Repair {
+	start: usize,
+	end: usize,
+	insert: &'static str,
+}
+
+impl Repair {
+	/// Whether the parser gets past the repaired spot now. Dropping a token
+	/// always counts, since the source gets shorter.
+	fn helps(&self, repaired: &str) -> bool {
+		let stop = parser::parse_Expr(repaired).err().map(|err| location(&err, repaired));
+		let stop = match (unmatched_paren(repaired), stop) {
+			(Some(paren), Some(stop)) => Some(paren.min(stop)),
+			(paren, stop) => paren.or(stop),
+		};
+		self.insert.is_empty() || stop.is_none_or(|stop| stop > self.start + self.insert.len())
+	}
+}
+
+/// Describes a parse error of `text` as a byte range and a message, and lists
+/// the repairs to try.
+fn diagnose(err: Error, text: &str) -> (usize, usize, String, Vec<Repair>) {
+	let at = |start, end| vec![
+		Repair { start, end: start, insert: NUMBER_HOLE },
+		Repair { start, end: start, insert: BOOL_HOLE },
+		Repair { start, end: start, insert: ")" },
+		Repair { start, end, insert: "" },
+	];
+	match err {
+		ParseError::InvalidToken { location } => {
+			let end = location + text[location..].chars().next().map_or(0, char::len_utf8);
+			let message = format!("unexpected character `{}`", &text[location..end]);
+			let repairs = vec![
+				Repair { start: location, end, insert: NUMBER_HOLE },
+				Repair { start: location, end, insert: BOOL_HOLE },
+				Repair { start: location, end, insert: "" },
+			];
+			(location, end, message, repairs)
+		}
+		ParseError::UnrecognizedToken { token: Some((start, (_, token), end)), expected } =>
+			(start, end, format!("unexpected `{}`{}", token, expected_tokens(&expected)), at(start, end)),
+		ParseError::UnrecognizedToken { token: None, expected } => {
+			let message = format!("unexpected end of input{}", expected_tokens(&expected));
+			let mut repairs = at(text.len(), text.len());
+			repairs.pop();
+			(text.len(), text.len(), message, repairs)
+		}
+		ParseError::ExtraToken { token: (start, (_, token), end) } =>
+			(start, end, format!("unexpected `{}` after the expression", token), vec![Repair { start, end, insert: "" }]),
+		ParseError::User { error: (start, end, message) } => {
+			let repairs = vec![
+				Repair { start, end, insert: NUMBER_HOLE },
+				Repair { start, end, insert: "" },
+			];
+			(start, end, message.to_string(), repairs)
+		}
+	}
+}
+
+/// Replaces `#` comments with spaces, byte for byte so that offsets stay the
+/// same. A comment runs to the end of the line, unless the `#` is part of a
+/// string or character literal.
+fn blank_comments(source: &str) -> String {
+	let mut text = String::with_capacity(source.len());
+	let mut closing = None;
+	for c in source.chars() {
+		let blank = match closing {
+			Some(end) if c == end => {
+				closing = None;
+				false
+			}
+			Some(end) => end == '\n',
+			None => {
+				closing = match c {
+					'"' => Some('"'),
+					'»' => Some('«'),
+					'\'' => Some('\''),
+					'›' => Some('‹'),
+					'#' => Some('\n'),
+					_ => None,
+				};
+				c == '#'
+			}
+		};
+		if blank {
+			text.extend((0..c.len_utf8()).map(|_| ' '));
+		} else {
+			text.push(c);
+		}
+	}
+	text
+}
+
+/// The offset of the first `)` in `text` that doesn't close a `(`.
+fn unmatched_paren(text: &str) -> Option<usize> {
+	let mut depth = 0;
+	let mut chars = text.char_indices();
+	while let Some((i, c)) = chars.next() {
+		let close = match c {
+			'"' => '"',
+			'»' => '«',
+			'\'' => '\'',
+			'›' => '‹',
+			'(' => {
+				depth += 1;
+				continue;
+			}
+			')' if depth == 0 => return Some(i),
+			')' => {
+				depth -= 1;
+				continue;
+			}
+			_ => continue,
+		};
+		// Skip string and character literals.
+		chars.by_ref().find(|&(_, c)| c == close);
+	}
+	None
+}
+
+/// Where in `text` the parser stopped.
+fn location(err: &Error, text: &str) -> usize {
+	match *err {
+		ParseError::InvalidToken { location } => location,
+		ParseError::UnrecognizedToken { token: Some((start, _, _)), .. } => start,
+		ParseError::ExtraToken { token: (start, _, _) } => start,
+		ParseError::User { error: (start, _, _) } => start,
+		ParseError::UnrecognizedToken { token: None, .. } => text.len(),
+	}
+}
+
+/// Names the expected tokens like they are written, leaving out the
+/// placeholders.
+fn expected_tokens(expected: &[String]) -> String {
+	let mut names = Vec::new();
+	for terminal in expected {
+		let name = match &terminal[..] {
+			r##"r#"[0-9]+"#"## => "a number".to_string(),
+			r##"r#"\"[^\"]*\""#"## | r##"r#"»[^«]*«"#"## => "a string".to_string(),
+			r##"r#"'[^']'"#"## | r##"r#"›[^‹]‹"#"## => "a character".to_string(),
+			"\"￼\"" | "\"￼￼\"" => continue,
+			quoted => format!("`{}`", quoted.trim_matches('"')),
+		};
+		if !names.contains(&name) {
+			names.push(name);
+		}
+	}
+	match names.len() {
+		0 => String::new(),
+		1 => format!(", expected {}", names[0]),
+		_ => {
+			let last = names.pop().unwrap();
+			format!(", expected {} or {}", names.join(", "), last)
+		}
+	}
+}
+
+/// The line and column of the byte `offset` in `source`, counting from 1.
+fn position(source: &str, offset: usize) -> (usize, usize) {
+	let before = &source[..offset];
+	let line_start = before.rfind('\n').map_or(0, |newline| newline + 1);
+	(before.matches('\n').count() + 1, before[line_start..].chars().count() + 1)
+}
+
+/// Maps offsets in the repaired source back to the original one. Each entry
+/// holds the offset from which on it applies and the difference to add.
+struct Shifts(Vec<(usize, isize)>);
+
+impl Shifts {
+	fn original(&self, offset: usize) -> usize {
+		let shift = self.0.iter().rev().find(|&&(from, _)| from <= offset).map_or(0, |&(_, shift)| shift);
+		(offset as isize + shift) as usize
+	}
+
+	fn record(&mut self, repair: &Repair) {
+		let start = self.original(repair.start);
+		let end = self.original(repair.end);
+		let after = repair.start + repair.insert.len();
+		let growth = after as isize - repair.end as isize;
+		// Entries within the replaced text are gone, the ones after it move.
+		self.0.retain(|&(from, _)| from < repair.start || from > repair.end);
+		for entry in &mut self.0 {
+			if entry.0 > repair.end {
+				*entry = ((entry.0 as isize + growth) as usize, entry.1 - growth);
+			}
+		}
+		self.0.push((repair.start, start as isize - repair.start as isize));
+		self.0.push((after, end as isize - after as isize));
+		self.0.sort_by_key(|&(from, _)| from);
+	}
+}
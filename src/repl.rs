@@ -0,0 +1,111 @@
+use std::env;
+use std::fmt::Display;
+use std::path::PathBuf;
+use rustyline::Editor;
+use rustyline::error::ReadlineError;
+
+use moses::{recover, Interpreter, Error};
+
+const PROMPT: &str = "> ";
+const CONTINUATION_PROMPT: &str = "… ";
+
+/// Runs the interactive loop until the user hits Ctrl-D.
+pub fn run() {
+	println!("Welcome to moses version {}.", env!("CARGO_PKG_VERSION"));
+	let interpreter = Interpreter::new();
+	let mut editor = Editor::<()>::new();
+	let history = history_file();
+	if let Some(ref path) = history {
+		// There is no history yet on the first start.
+		let _ = editor.load_history(path);
+	}
+	let mut input = String::new();
+	loop {
+		let prompt = if input.is_empty() { PROMPT } else { CONTINUATION_PROMPT };
+		match editor.readline(prompt) {
+			Ok(line) => {
+				input.push_str(&line);
+				input.push('\n');
+				if needs_continuation(&input) {
+					continue;
+				}
+				if !input.trim().is_empty() {
+					editor.add_history_entry(input.trim_end());
+					execute(&interpreter, input.trim());
+				}
+				input.clear();
+			}
+			// Ctrl-C discards the current input, but keeps the session.
+			Err(ReadlineError::Interrupted) => input.clear(),
+			Err(ReadlineError::Eof) => break,
+			Err(err) => {
+				eprintln!("error: {}", err);
+				break;
+			}
+		}
+	}
+	if let Some(ref path) = history {
+		if let Err(err) = editor.save_history(path) {
+			eprintln!("error: could not save history: {}", err);
+		}
+	}
+}
+
+/// Runs a meta-command like `:ast 1 + 2` or evaluates `input` otherwise.
+fn execute(interpreter: &Interpreter, input: &str) {
+	if !input.starts_with(':') {
+		return report(interpreter.eval_str(input));
+	}
+	let (command, argument) = match input.find(char::is_whitespace) {
+		Some(i) => (&input[..i], input[i..].trim()),
+		None => (input, ""),
+	};
+	match command {
+		":ast" => {
+			let (expr, errors) = recover::parse(argument);
+			println!("{:?}", expr);
+			if !errors.is_empty() {
+				eprintln!("{}", Error::Parse(errors));
+			}
+		}
+		":type" => report(interpreter.check_str(argument)),
+		":load" => report(interpreter.eval_file(argument)),
+		_ => eprintln!("error: unknown command {}, try :ast, :type or :load", command),
+	}
+}
+
+fn report<T: Display>(result: Result<T, Error>) {
+	match result {
+		Ok(value) => println!("{}", value),
+		Err(err) => eprintln!("{}", err),
+	}
+}
+
+fn history_file() -> Option<PathBuf> {
+	env::var_os("HOME").map(|home| PathBuf::from(home).join(".moses_history"))
+}
+
+/// Whether `input` has unclosed parentheses, so that the REPL has to ask for
+/// another line. Parentheses within string and character literals or
+/// comments don't count.
+pub fn needs_continuation(input: &str) -> bool {
+	let mut depth = 0;
+	let mut literal_end = None;
+	for c in input.chars() {
+		match literal_end {
+			Some(end) if c == end => literal_end = None,
+			Some(_) => {}
+			None => match c {
+				'(' => depth += 1,
+				')' => depth -= 1,
+				'"' => literal_end = Some('"'),
+				'\'' => literal_end = Some('\''),
+				'»' => literal_end = Some('«'),
+				'›' => literal_end = Some('‹'),
+				'#' => literal_end = Some('\n'),
+				_ => {}
+			},
+		}
+	}
+	depth > 0
+}
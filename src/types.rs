@@ -0,0 +1,73 @@
+use std::fmt::{Display, Formatter, Error};
+use ast::{Expr, Opcode, MAX_DEPTH};
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+pub enum Type {
+	Number,
+	Bool,
+	Str,
+	Char,
+}
+
+impl Display for Type {
+	fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+		write!(fmt, "{:?}", self)
+	}
+}
+
+#[derive(Clone, PartialEq, Debug)]
+pub struct TypeError {
+	pub message: String,
+}
+
+impl Display for TypeError {
+	fn fmt(&self, fmt: &mut Formatter) -> Result<(), Error> {
+		write!(fmt, "type error: {}", self.message)
+	}
+}
+
+/// Infers the type of `expr` without evaluating it. Expressions nested
+/// deeper than `ast::MAX_DEPTH` are an error.
+pub fn type_of(expr: &Expr) -> Result<Type, TypeError> {
+	if expr.depth() > MAX_DEPTH {
+		return Err(TypeError { message: format!("expressions nested deeper than {}", MAX_DEPTH) });
+	}
+	infer(expr)
+}
+
+fn infer(expr: &Expr) -> Result<Type, TypeError> {
+	use ast::Opcode::*;
+	match *expr {
+		Expr::Number(_) => Ok(Type::Number),
+		Expr::True | Expr::False => Ok(Type::Bool),
+		Expr::Str(_) => Ok(Type::Str),
+		Expr::Char(_) => Ok(Type::Char),
+		Expr::Error => Err(TypeError { message: "syntax error".to_string() }),
+		Expr::Op(op, ref operands) => match op {
+			Add | Sub | Mul | Div => {
+				expect_operands(op, operands, Type::Number)?;
+				Ok(Type::Number)
+			}
+			Eq | Neq | Greater | Lesser | Geq | Leq => {
+				expect_operands(op, operands, Type::Number)?;
+				Ok(Type::Bool)
+			}
+			And | Or | Not => {
+				expect_operands(op, operands, Type::Bool)?;
+				Ok(Type::Bool)
+			}
+		},
+	}
+}
+
+fn expect_operands(op: Opcode, operands: &[Box<Expr>], expected: Type) -> Result<(), TypeError> {
+	for operand in operands {
+		let found = infer(operand)?;
+		if found != expected {
+			return Err(TypeError {
+				message: format!("{:?} expects {}, found {}", op, expected, found),
+			});
+		}
+	}
+	Ok(())
+}
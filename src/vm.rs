@@ -0,0 +1,53 @@
+use ast::Opcode;
+use bytecode::{Chunk, Instruction};
+use eval::{self, Value, MosesPanic};
+
+/// Runs a compiled program on a value stack.
+pub fn run(chunk: &Chunk) -> Result<Value, MosesPanic> {
+	let mut stack: Vec<Value> = Vec::new();
+	let mut pc = 0;
+	loop {
+		let instruction = chunk.code[pc];
+		pc += 1;
+		match instruction {
+			Instruction::Constant(index) => stack.push(chunk.constants[index].clone()),
+			Instruction::Op(Opcode::Not) => {
+				let operand = bool_operand(Opcode::Not, stack.pop().unwrap())?;
+				stack.push(Value::Bool(!operand));
+			}
+			Instruction::Op(op) => {
+				let snd = number_operand(op, stack.pop().unwrap())?;
+				let fst = number_operand(op, stack.pop().unwrap())?;
+				stack.push(eval::apply_numeric(op, fst, snd)?);
+			}
+			Instruction::JumpIfFalse(target) => {
+				if !bool_operand(Opcode::And, stack.last().unwrap().clone())? {
+					pc = target;
+				}
+			}
+			Instruction::JumpIfTrue(target) => {
+				if bool_operand(Opcode::Or, stack.last().unwrap().clone())? {
+					pc = target;
+				}
+			}
+			Instruction::Pop => {
+				stack.pop();
+			}
+			Instruction::Return => return Ok(stack.pop().unwrap()),
+		}
+	}
+}
+
+fn bool_operand(op: Opcode, value: Value) -> Result<bool, MosesPanic> {
+	match value {
+		Value::Bool(b) => Ok(b),
+		other => Err(eval::type_mismatch(op, "a boolean", other)),
+	}
+}
+
+fn number_operand(op: Opcode, value: Value) -> Result<i32, MosesPanic> {
+	match value {
+		Value::Number(n) => Ok(n),
+		other => Err(eval::type_mismatch(op, "a number", other)),
+	}
+}
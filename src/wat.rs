@@ -0,0 +1,196 @@
+use std::fmt::Write;
+use ast::{Expr, Opcode};
+use types::Type;
+
+/// Where string data starts in linear memory. The bytes before it are
+/// scratch space for the runtime: an iovec at 0, the number of written bytes
+/// at 8 and the digits of a number from 16 on.
+const DATA_START: usize = 1024;
+/// The size of a page of linear memory.
+const PAGE_SIZE: usize = 65536;
+
+/// Translates a type checked expression of type `ty` into a WebAssembly text
+/// module. The module uses WASI to print the value of the expression when it
+/// is started and to report panics on stderr with exit status 1, so it runs in
+/// any engine supporting WASI preview 1.
+pub fn compile(expr: &Expr, ty: Type) -> String {
+	let mut generator = Generator { data: Vec::new(), code: String::new() };
+	let runtime = generator.runtime();
+	match ty {
+		Type::Number => {
+			generator.expression(expr);
+			generator.code.push_str("\t\tcall $print_number\n");
+		}
+		Type::Bool => {
+			generator.expression(expr);
+			generator.code.push_str("\t\tcall $print_bool\n");
+		}
+		// Strings and characters only occur as literals, there are no
+		// operations on them yet.
+		Type::Str | Type::Char => {
+			let (literal, open, close) = match *expr {
+				Expr::Str(ref s) => (s.clone(), "»", "«"),
+				Expr::Char(c) => (c.to_string(), "›", "‹"),
+				_ => unreachable!("{:?} is not a literal", expr),
+			};
+			let literal = format!("{}{}{}\n", open, literal, close);
+			let (offset, len) = generator.string(&literal);
+			writeln!(generator.code, "\t\ti32.const 1\n\t\ti32.const {}\n\t\ti32.const {}\n\t\tcall $write", offset, len).unwrap();
+		}
+	}
+	let mut module = String::from("(module\n");
+	module.push_str(&runtime);
+	module.push_str("\t(func (export \"_start\")\n");
+	module.push_str(&generator.code);
+	module.push_str("\t)\n");
+	module.push_str("\t(data (i32.const ");
+	module.push_str(&DATA_START.to_string());
+	module.push_str(") \"");
+	for byte in &generator.data {
+		write!(module, "\\{:02x}", byte).unwrap();
+	}
+	module.push_str("\")\n");
+	// Whole pages of 64 KiB, enough for the scratch space and the data.
+	let pages = (DATA_START + generator.data.len()).div_ceil(PAGE_SIZE);
+	writeln!(module, "\t(memory (export \"memory\") {})", pages).unwrap();
+	module.push_str(")\n");
+	module
+}
+
+struct Generator {
+	data: Vec<u8>,
+	code: String,
+}
+
+impl Generator {
+	/// Stores `s` in the data segment and returns its address and length.
+	fn string(&mut self, s: &str) -> (usize, usize) {
+		let offset = DATA_START + self.data.len();
+		self.data.extend_from_slice(s.as_bytes());
+		(offset, s.len())
+	}
+
+	/// Emits instructions that leave the value of `expr` on the stack.
+	/// Numbers are i32s, booleans are i32s that are 0 or 1.
+	fn expression(&mut self, expr: &Expr) {
+		match *expr {
+			Expr::Number(n) => writeln!(self.code, "\t\ti32.const {}", n).unwrap(),
+			Expr::True => self.code.push_str("\t\ti32.const 1\n"),
+			Expr::False => self.code.push_str("\t\ti32.const 0\n"),
+			Expr::Str(_) | Expr::Char(_) | Expr::Error => unreachable!("{:?} is not a number or boolean", expr),
+			Expr::Op(Opcode::And, ref operands) => {
+				self.expression(&operands[0]);
+				self.code.push_str("\t\tif (result i32)\n");
+				self.expression(&operands[1]);
+				self.code.push_str("\t\telse\n\t\ti32.const 0\n\t\tend\n");
+			}
+			Expr::Op(Opcode::Or, ref operands) => {
+				self.expression(&operands[0]);
+				self.code.push_str("\t\tif (result i32)\n\t\ti32.const 1\n\t\telse\n");
+				self.expression(&operands[1]);
+				self.code.push_str("\t\tend\n");
+			}
+			Expr::Op(op, ref operands) => {
+				for operand in operands {
+					self.expression(operand);
+				}
+				let instruction = match op {
+					Opcode::Add => "call $add",
+					Opcode::Sub => "call $sub",
+					Opcode::Mul => "call $mul",
+					Opcode::Div => "call $div",
+					Opcode::Not => "i32.eqz",
+					Opcode::Eq => "i32.eq",
+					Opcode::Neq => "i32.ne",
+					Opcode::Greater => "i32.gt_s",
+					Opcode::Lesser => "i32.lt_s",
+					Opcode::Geq => "i32.ge_s",
+					Opcode::Leq => "i32.le_s",
+					Opcode::And | Opcode::Or => unreachable!(),
+				};
+				writeln!(self.code, "\t\t{}", instruction).unwrap();
+			}
+		}
+	}
+
+	/// The imports and helper functions every module needs.
+	fn runtime(&mut self) -> String {
+		let (panic, panic_len) = self.string("panic: ");
+		let (overflow, overflow_len) = self.string("arithmetic overflow in ");
+		let (division, division_len) = self.string("division by zero in ");
+		let (comma, comma_len) = self.string(", ");
+		let (close, close_len) = self.string("]\n");
+		let (true_, true_len) = self.string("True\n");
+		let (false_, false_len) = self.string("False\n");
+		let mut ops = String::new();
+		for &(name, operator) in &[("add", "Add"), ("sub", "Sub"), ("mul", "Mul")] {
+			let (op, op_len) = self.string(&format!("{}[", operator));
+			write!(ops, r#"	(func ${name} (param $fst i32) (param $snd i32) (result i32)
+		(local $result i64)
+		(local.set $result (i64.{name} (i64.extend_i32_s (local.get $fst)) (i64.extend_i32_s (local.get $snd))))
+		(if (i64.ne (local.get $result) (i64.extend_i32_s (i32.wrap_i64 (local.get $result))))
+			(then (call $panic (i32.const {overflow}) (i32.const {overflow_len}) (i32.const {op}) (i32.const {op_len}) (local.get $fst) (local.get $snd))))
+		(i32.wrap_i64 (local.get $result))
+	)
+"#, name = name, overflow = overflow, overflow_len = overflow_len, op = op, op_len = op_len).unwrap();
+		}
+		let (div, div_len) = self.string("Div[");
+		format!(r#"	(import "wasi_snapshot_preview1" "fd_write" (func $fd_write (param i32 i32 i32 i32) (result i32)))
+	(import "wasi_snapshot_preview1" "proc_exit" (func $proc_exit (param i32)))
+	(func $write (param $fd i32) (param $ptr i32) (param $len i32)
+		(i32.store (i32.const 0) (local.get $ptr))
+		(i32.store (i32.const 4) (local.get $len))
+		(drop (call $fd_write (local.get $fd) (i32.const 0) (i32.const 1) (i32.const 8)))
+	)
+	(func $write_number (param $fd i32) (param $n i32)
+		(local $rest i64)
+		(local $pos i32)
+		(local.set $rest (i64.extend_i32_s (local.get $n)))
+		(if (i64.lt_s (local.get $rest) (i64.const 0))
+			(then (local.set $rest (i64.sub (i64.const 0) (local.get $rest)))))
+		(local.set $pos (i32.const 48))
+		(loop $digits
+			(local.set $pos (i32.sub (local.get $pos) (i32.const 1)))
+			(i32.store8 (local.get $pos) (i32.add (i32.const 48) (i32.wrap_i64 (i64.rem_u (local.get $rest) (i64.const 10)))))
+			(local.set $rest (i64.div_u (local.get $rest) (i64.const 10)))
+			(br_if $digits (i64.ne (local.get $rest) (i64.const 0))))
+		(if (i32.lt_s (local.get $n) (i32.const 0))
+			(then
+				(local.set $pos (i32.sub (local.get $pos) (i32.const 1)))
+				(i32.store8 (local.get $pos) (i32.const 45))))
+		(call $write (local.get $fd) (local.get $pos) (i32.sub (i32.const 48) (local.get $pos)))
+	)
+	(func $panic (param $message i32) (param $message_len i32) (param $op i32) (param $op_len i32) (param $fst i32) (param $snd i32)
+		(call $write (i32.const 2) (i32.const {panic}) (i32.const {panic_len}))
+		(call $write (i32.const 2) (local.get $message) (local.get $message_len))
+		(call $write (i32.const 2) (local.get $op) (local.get $op_len))
+		(call $write_number (i32.const 2) (local.get $fst))
+		(call $write (i32.const 2) (i32.const {comma}) (i32.const {comma_len}))
+		(call $write_number (i32.const 2) (local.get $snd))
+		(call $write (i32.const 2) (i32.const {close}) (i32.const {close_len}))
+		(call $proc_exit (i32.const 1))
+	)
+{ops}	(func $div (param $fst i32) (param $snd i32) (result i32)
+		(if (i32.eqz (local.get $snd))
+			(then (call $panic (i32.const {division}) (i32.const {division_len}) (i32.const {div}) (i32.const {div_len}) (local.get $fst) (local.get $snd))))
+		(if (i32.and (i32.eq (local.get $fst) (i32.const -2147483648)) (i32.eq (local.get $snd) (i32.const -1)))
+			(then (call $panic (i32.const {overflow}) (i32.const {overflow_len}) (i32.const {div}) (i32.const {div_len}) (local.get $fst) (local.get $snd))))
+		(i32.div_s (local.get $fst) (local.get $snd))
+	)
+	(func $print_number (param $n i32)
+		(call $write_number (i32.const 1) (local.get $n))
+		(call $write (i32.const 1) (i32.const {newline}) (i32.const 1))
+	)
+	(func $print_bool (param $b i32)
+		(if (local.get $b)
+			(then (call $write (i32.const 1) (i32.const {true_}) (i32.const {true_len})))
+			(else (call $write (i32.const 1) (i32.const {false_}) (i32.const {false_len}))))
+	)
+"#,
+			panic = panic, panic_len = panic_len, comma = comma, comma_len = comma_len,
+			close = close, close_len = close_len, ops = ops, division = division,
+			division_len = division_len, div = div, div_len = div_len, overflow = overflow,
+			overflow_len = overflow_len, newline = close + 1, true_ = true_,
+			true_len = true_len, false_ = false_, false_len = false_len)
+	}
+}
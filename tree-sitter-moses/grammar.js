@@ -0,0 +1,64 @@
+// A tree-sitter grammar for Moses, following the tiers in src/parser.lalrpop.
+//
+// Unlike the Rust parser, it doesn't tell boolean from arithmetic
+// expressions, so editors can highlight ill-typed code too. The corpus in
+// test/corpus is checked against the Rust parser by the `tree_sitter_corpus`
+// test, which keeps the two grammars from drifting apart. See README.md for
+// what isn't checked.
+
+const PREC = {
+	or: 1,
+	and: 2,
+	not: 3,
+	compare: 4,
+	sum: 5,
+	product: 6,
+};
+
+module.exports = grammar({
+	name: 'moses',
+
+	extras: $ => [/\s/, $.comment],
+
+	rules: {
+		source_file: $ => $._expression,
+
+		_expression: $ => choice(
+			$.number,
+			$.true,
+			$.false,
+			$.string,
+			$.character,
+			$.unary_expression,
+			$.binary_expression,
+			$._parenthesized_expression,
+		),
+
+		// Hidden, like in the Rust syntax tree.
+		_parenthesized_expression: $ => seq('(', $._expression, ')'),
+
+		unary_expression: $ => prec(PREC.not, seq('not', field('operand', $._expression))),
+
+		binary_expression: $ => {
+			const table = [
+				[PREC.or, 'or'],
+				[PREC.and, 'and'],
+				[PREC.compare, choice('==', '!=', '≠', '<=', '≤', '>=', '≥', '<', '>')],
+				[PREC.sum, choice('+', '-')],
+				[PREC.product, choice('*', '·', '/', '÷')],
+			];
+			return choice(...table.map(([precedence, operator]) => prec.left(precedence, seq(
+				field('left', $._expression),
+				operator,
+				field('right', $._expression),
+			))));
+		},
+
+		number: $ => /[0-9]+/,
+		true: $ => 'True',
+		false: $ => 'False',
+		string: $ => token(choice(/"[^"]*"/, /»[^«]*«/)),
+		character: $ => token(choice(/'[^']'/, /›[^‹]‹/)),
+		comment: $ => token(seq('#', /.*/)),
+	},
+});
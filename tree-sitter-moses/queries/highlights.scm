@@ -0,0 +1,38 @@
+(number) @number
+(string) @string
+(character) @character
+(comment) @comment
+
+[
+  (true)
+  (false)
+] @boolean
+
+[
+  "and"
+  "or"
+  "not"
+] @keyword.operator
+
+[
+  "+"
+  "-"
+  "*"
+  "·"
+  "/"
+  "÷"
+  "=="
+  "!="
+  "≠"
+  "<"
+  ">"
+  "<="
+  "≤"
+  ">="
+  "≥"
+] @operator
+
+[
+  "("
+  ")"
+] @punctuation.bracket